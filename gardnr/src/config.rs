@@ -0,0 +1,48 @@
+use crate::utils::config_dir;
+use serde::Deserialize;
+use std::fs;
+
+/// Name of gardnr's settings file inside its config directory.
+pub const CONFIG_FILE: &str = "config.toml";
+
+/// User defaults read from `config.toml` in gardnr's config directory.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct Config {
+    pub python: PythonConfig,
+    pub node: NodeConfig,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PythonConfig {
+    /// Default environment/dependency tool: pip, uv, poetry, pdm, hatch, conda or mamba
+    pub tool: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct NodeConfig {
+    /// Default package manager: npm, yarn, pnpm or bun
+    pub pm: Option<String>,
+    /// Task runner for projects with several Node.js components: turbo or nx
+    pub task_runner: Option<String>,
+}
+
+impl Config {
+    /// Load the config file. A missing file gives the defaults; so does a
+    /// broken one, after a warning.
+    pub fn load() -> Config {
+        let Some(path) = config_dir().map(|dir| dir.join(CONFIG_FILE)) else {
+            return Config::default();
+        };
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Config::default();
+        };
+
+        toml::from_str(&content).unwrap_or_else(|e| {
+            eprintln!("⚠️  Ignoring {}: {}", path.display(), e);
+            Config::default()
+        })
+    }
+}
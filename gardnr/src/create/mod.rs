@@ -1,15 +1,106 @@
-use crate::tech::node::scaffold_js_project;
-use crate::tech::python::scaffold_python_project;
-use std::{fs, io, path::PathBuf, process::Command};
+use crate::manifest::{ComponentManifest, Manifest};
+use crate::tech::TechSpec;
+use crate::tech::node::package_json::PACKAGE_JSON;
+use crate::tech::node::pm::PackageManager;
+use crate::tech::node::workspace::{TaskRunner, create_workspace};
+use crate::tech::node::{self, scaffold_js_project};
+use crate::tech::python::env::PyTool;
+use crate::tech::python::{self, ScaffoldedPython, scaffold_python_project};
+use crate::template::TemplateContext;
+use crate::template::repo::{scaffold_from_template, template_answers};
+use crate::template::vars::{self, TemplateVar};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 pub struct Component {
     pub name: String,
     pub tech: String,
+    /// Answers to the variables the tech or template declares
+    pub vars: BTreeMap<String, String>,
 }
+
+/// Values given with `--var`: `key=value` applies to every component,
+/// `component.key=value` to just that one.
+pub struct VarOverrides {
+    pairs: Vec<(String, String)>,
+}
+
+impl VarOverrides {
+    pub fn parse(args: &[String]) -> io::Result<VarOverrides> {
+        let pairs = args
+            .iter()
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| match arg.split_once('=') {
+                Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid --var '{}', expected key=value", arg),
+                )),
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(VarOverrides { pairs })
+    }
+
+    pub fn for_component(&self, component_name: &str) -> BTreeMap<String, String> {
+        let prefix = format!("{}.", component_name);
+        let mut vars = BTreeMap::new();
+
+        for (key, value) in &self.pairs {
+            if !key.contains('.') {
+                vars.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+        for (key, value) in &self.pairs {
+            if let Some(key) = key.strip_prefix(&prefix) {
+                vars.insert(key.to_string(), value.clone());
+            }
+        }
+
+        vars
+    }
+}
+
+impl Component {
+    /// Parse a `name=tech` spec such as `api=fastapi` or
+    /// `api=template:git+file:///srv/templates/api.git#v2`.
+    pub fn parse(spec: &str) -> Option<Component> {
+        let (name, tech) = spec.split_once('=')?;
+        let valid_name = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+
+        if !valid_name || tech.is_empty() {
+            return None;
+        }
+
+        Some(Component {
+            name: name.to_string(),
+            tech: tech.to_string(),
+            vars: BTreeMap::new(),
+        })
+    }
+}
+/// Tools chosen on the command line for every component they apply to;
+/// `None` means auto-detect.
+#[derive(Default, Clone, Copy)]
+pub struct Tools {
+    pub python: Option<PyTool>,
+    pub node: Option<PackageManager>,
+    /// Runs the root scripts of a JavaScript workspace; plain scripts if `None`
+    pub task_runner: Option<TaskRunner>,
+}
+
 pub struct Project {
     pub name: String,
     pub path: String,
     pub components: Vec<Component>,
+    pub tools: Tools,
 }
 
 pub fn create_project(project: Project) -> io::Result<()> {
@@ -35,13 +126,32 @@ pub fn create_project(project: Project) -> io::Result<()> {
     fs::create_dir(&project_dir)?;
     println!("Created project directory: {}", project_dir.display());
 
+    let mut manifest = Manifest::new(&project.name);
+
     // Create and scaffold component directories
     for component in &project.components {
-        let tech_lower = component.tech.to_lowercase();
+        let tech_lower = TechSpec::parse(&component.tech).name.to_lowercase();
         // For Django and Vite-based projects, let the scaffolding tools create the directory
         if !matches!(
             tech_lower.as_str(),
-            "django" | "react" | "vue" | "svelte" | "nextjs" | "next" | "nuxt"
+            "django"
+                | "react"
+                | "vue"
+                | "svelte"
+                | "sveltekit"
+                | "svelte-kit"
+                | "nextjs"
+                | "next"
+                | "nuxt"
+                | "angular"
+                | "solid"
+                | "solidjs"
+                | "preact"
+                | "qwik"
+                | "astro"
+                | "remix"
+                | "ember"
+                | "emberjs"
         ) {
             let component_dir = project_dir.join(&component.name);
             fs::create_dir_all(&component_dir)?;
@@ -49,15 +159,53 @@ pub fn create_project(project: Project) -> io::Result<()> {
         }
 
         // Scaffold technology-specific structure
-        if let Err(e) = scaffold_component(&project_dir, &component.name, &component.tech) {
+        let mut entry = ComponentManifest {
+            tech: component.tech.clone(),
+            vars: component.vars.clone(),
+            ..Default::default()
+        };
+        if let Err(e) = scaffold_component(&project_dir, component, project.tools, &mut entry) {
             eprintln!("Warning: Failed to scaffold {}: {}", component.tech, e);
         }
+        manifest.components.insert(component.name.clone(), entry);
+    }
+
+    if let Err(e) = create_js_workspace(&project_dir, &manifest, project.tools) {
+        eprintln!("Warning: Failed to create the JavaScript workspace: {}", e);
     }
 
+    manifest.save(&project_dir)?;
+
     Ok(())
 }
 
-pub fn questionnaire() -> io::Result<()> {
+/// Put the project's Node.js components into one workspace when there is
+/// more than one of them.
+fn create_js_workspace(project_dir: &Path, manifest: &Manifest, tools: Tools) -> io::Result<()> {
+    let members: Vec<(&String, &String)> = manifest
+        .components
+        .iter()
+        .filter_map(|(name, entry)| Some((name, entry.pm.as_ref()?)))
+        .filter(|(name, _)| project_dir.join(name).join(PACKAGE_JSON).is_file())
+        .collect();
+    if members.len() < 2 {
+        return Ok(());
+    }
+
+    let Some(pm) = PackageManager::parse(members[0].1) else {
+        return Ok(());
+    };
+    let names: Vec<String> = members.iter().map(|(name, _)| name.to_string()).collect();
+    create_workspace(
+        project_dir,
+        &manifest.project.name,
+        &names,
+        pm,
+        tools.task_runner,
+    )
+}
+
+pub fn questionnaire(overrides: &VarOverrides, tools: Tools) -> io::Result<()> {
     println!("Enter project name (cannot be empty):");
     let mut project_name = String::new();
     io::stdin()
@@ -110,9 +258,34 @@ pub fn questionnaire() -> io::Result<()> {
             continue;
         }
 
+        // Ask for whatever the tech or template lets us customise
+        let given = overrides.for_component(&component_name);
+        let mut context = TemplateContext::new(&project_name, &component_name);
+        let answers = match component_tech.strip_prefix("template:") {
+            Some(spec) => template_answers(spec, &mut context, &given),
+            None => vars::resolve(
+                &tech_variables(&component_tech),
+                None,
+                &given,
+                true,
+                &mut context,
+            ),
+        };
+        let vars = match answers {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!(
+                    "Warning: Could not collect variables for {}: {}",
+                    component_name, e
+                );
+                given
+            }
+        };
+
         components.push(Component {
             name: component_name,
             tech: component_tech,
+            vars,
         });
     }
 
@@ -120,32 +293,85 @@ pub fn questionnaire() -> io::Result<()> {
         name: project_name,
         path: project_path,
         components,
+        tools,
     };
 
     create_project(new_project)
 }
 
-fn scaffold_component(base_dir: &PathBuf, component_name: &str, tech: &str) -> io::Result<()> {
+/// Variables the built-in scaffold for `tech` declares.
+fn tech_variables(tech: &str) -> Vec<TemplateVar> {
+    let spec = TechSpec::parse(tech);
+    let mut variables = python::variables(&spec.name);
+    variables.extend(node::variables(&spec.name));
+    variables
+}
+
+fn scaffold_component(
+    base_dir: &Path,
+    component: &Component,
+    tools: Tools,
+    entry: &mut ComponentManifest,
+) -> io::Result<()> {
+    let component_name = component.name.as_str();
+    let tech = component.tech.as_str();
     let component_dir = base_dir.join(component_name);
-    let tech_lower = tech.to_lowercase();
 
-    match tech_lower.as_str() {
+    // Template sources are paths/URLs, so match them before lowercasing
+    if let Some(spec) = tech.strip_prefix("template:") {
+        let project_name = base_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("myproject");
+        let mut context = TemplateContext::new(project_name, component_name);
+        let scaffolded =
+            scaffold_from_template(spec, &component_dir, &mut context, &component.vars)?;
+
+        entry.tech = "template".to_string();
+        entry.template = Some(scaffolded.source);
+        entry.revision = scaffolded.revision;
+        return Ok(());
+    }
+
+    let spec = TechSpec::parse(tech);
+    match spec.name.to_lowercase().as_str() {
         // Python-based technologies
-        "django" | "flask" | "fastapi" | "pyramid" | "python" => {
-            scaffold_python_project(base_dir, component_name, tech)
-        }
+        "django" | "flask" | "fastapi" | "pyramid" | "litestar" | "starlette" | "sanic"
+        | "quart" | "streamlit" | "gradio" | "python" | "python-cli" => scaffold_python_project(
+            base_dir,
+            component_name,
+            &spec,
+            tools.python,
+            &component.vars,
+        )
+        .map(|scaffolded| record_python(entry, scaffolded)),
         // JavaScript/Node.js-based technologies
-        "react" | "vue" | "svelte" | "node" | "nodejs" | "express" | "nextjs" | "next" | "nuxt" => {
-            scaffold_js_project(base_dir, component_name, tech)
+        "react" | "vue" | "svelte" | "sveltekit" | "svelte-kit" | "node" | "nodejs" | "express"
+        | "nextjs" | "next" | "nuxt" | "angular" | "solid" | "solidjs" | "preact" | "qwik"
+        | "astro" | "remix" | "ember" | "emberjs" => {
+            scaffold_js_project(base_dir, component_name, &spec, tools.node, &component.vars)
+                .map(|pm| record_node(entry, pm))
+        }
+        "rust" => {
+            vars::warn_undeclared(&[], &component.vars, component_name);
+            scaffold_rust(&component_dir, component_name)
         }
-        "rust" => scaffold_rust(&component_dir, component_name),
         _ => {
             // Check if it might be a Python library
-            if is_likely_python_package(tech) {
-                scaffold_python_project(base_dir, component_name, tech)
-            } else if is_likely_node_package(tech) {
-                scaffold_js_project(base_dir, component_name, tech)
+            if is_likely_python_package(&spec.name) {
+                scaffold_python_project(
+                    base_dir,
+                    component_name,
+                    &spec,
+                    tools.python,
+                    &component.vars,
+                )
+                .map(|scaffolded| record_python(entry, scaffolded))
+            } else if is_likely_node_package(&spec.name) {
+                scaffold_js_project(base_dir, component_name, &spec, tools.node, &component.vars)
+                    .map(|pm| record_node(entry, pm))
             } else {
+                vars::warn_undeclared(&[], &component.vars, component_name);
                 println!(
                     "→ No specific scaffolding for '{}', created basic directory",
                     tech
@@ -156,6 +382,17 @@ fn scaffold_component(base_dir: &PathBuf, component_name: &str, tech: &str) -> i
     }
 }
 
+/// Record which package manager a Node.js component uses.
+fn record_node(entry: &mut ComponentManifest, pm: Option<PackageManager>) {
+    entry.pm = pm.map(|pm| pm.command().to_string());
+}
+
+/// Record how a Python component's environment is managed.
+fn record_python(entry: &mut ComponentManifest, scaffolded: ScaffoldedPython) {
+    entry.py_tool = scaffolded.tool.map(|tool| tool.name().to_string());
+    entry.conda_env = scaffolded.conda_env;
+}
+
 fn is_likely_python_package(tech: &str) -> bool {
     // List of common Python packages/frameworks
     let python_packages = [
@@ -219,7 +456,7 @@ fn is_likely_node_package(tech: &str) -> bool {
         .any(|&pkg| tech.to_lowercase().contains(pkg))
 }
 
-fn scaffold_rust(component_dir: &PathBuf, component_name: &str) -> io::Result<()> {
+fn scaffold_rust(component_dir: &Path, component_name: &str) -> io::Result<()> {
     println!("→ Scaffolding Rust project");
 
     let output = Command::new("cargo").arg("--version").output();
@@ -239,9 +476,52 @@ fn scaffold_rust(component_dir: &PathBuf, component_name: &str) -> io::Result<()
         .status()?;
 
     if !status.success() {
-        return Err(io::Error::new(io::ErrorKind::Other, "cargo new failed"));
+        return Err(io::Error::other("cargo new failed"));
     }
 
     println!("→ Rust project scaffolded successfully");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides(args: &[&str]) -> VarOverrides {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        VarOverrides::parse(&args).unwrap()
+    }
+
+    #[test]
+    fn component_keys_win_over_plain_keys() {
+        for args in [
+            ["port=8000", "api.port=9000", "debug=true"],
+            ["api.port=9000", "port=8000", "debug=true"],
+        ] {
+            let vars = overrides(&args).for_component("api");
+            assert_eq!(vars.get("port").map(String::as_str), Some("9000"));
+            assert_eq!(vars.get("debug").map(String::as_str), Some("true"));
+        }
+
+        let web = overrides(&["port=8000", "api.port=9000"]).for_component("web");
+        assert_eq!(web.get("port").map(String::as_str), Some("8000"));
+    }
+
+    #[test]
+    fn values_may_contain_equals_signs() {
+        let vars =
+            overrides(&["api.dsn=postgres://u:p@db/app?sslmode=require"]).for_component("api");
+        assert_eq!(
+            vars.get("dsn").map(String::as_str),
+            Some("postgres://u:p@db/app?sslmode=require")
+        );
+    }
+
+    #[test]
+    fn rejects_vars_without_key() {
+        for arg in ["port", "=8000"] {
+            let error = VarOverrides::parse(&[arg.to_string()]).err().unwrap();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        }
+    }
+}
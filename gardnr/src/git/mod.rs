@@ -0,0 +1,49 @@
+use std::{io, path::Path, process::Command};
+
+/// Clone `url` into `dest` and check out `revision` if one is given.
+///
+/// Works against local paths, `file://` URLs and bare repositories, so no
+/// network access is needed for templates kept on disk.
+pub fn clone(url: &str, revision: Option<&str>, dest: &Path) -> io::Result<()> {
+    println!("→ Running git clone {}", url);
+
+    let status = Command::new("git")
+        .args(["clone", "--quiet", url])
+        .arg(dest)
+        .status()?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!("git clone {} failed", url)));
+    }
+
+    if let Some(revision) = revision {
+        let status = Command::new("git")
+            .args(["checkout", "--quiet", revision])
+            .current_dir(dest)
+            .status()?;
+
+        if !status.success() {
+            return Err(io::Error::other(format!(
+                "git checkout {} failed",
+                revision
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// The commit currently checked out in `dir`, if it is a git work tree.
+pub fn head_revision(dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(dir)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
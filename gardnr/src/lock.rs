@@ -0,0 +1,37 @@
+use crate::manifest::{MANIFEST_FILE, Manifest};
+use crate::tech::python::env::{PyTool, PythonEnv};
+use std::{io, path::Path};
+
+/// Refresh the lock of every Python component of the project in
+/// `project_dir`, with the tool recorded for it in the manifest.
+pub fn lock_project(project_dir: &Path) -> io::Result<()> {
+    let manifest = Manifest::load(project_dir).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("{}: {}", project_dir.join(MANIFEST_FILE).display(), e),
+        )
+    })?;
+
+    let mut locked = 0;
+    for (name, component) in &manifest.components {
+        let Some(tool) = component.py_tool.as_deref().and_then(PyTool::parse) else {
+            continue;
+        };
+        let env_name = component
+            .conda_env
+            .clone()
+            .unwrap_or_else(|| format!("{}-{}", manifest.project.name, name));
+
+        println!("→ Locking {} ({})", name, tool.name());
+        let env = PythonEnv::open(tool, &project_dir.join(name), &env_name);
+        match env.lock() {
+            Ok(()) => locked += 1,
+            Err(e) => eprintln!("⚠️  Could not lock {}: {}", name, e),
+        }
+    }
+
+    if locked == 0 {
+        println!("→ No Python components were locked");
+    }
+    Ok(())
+}
@@ -1,22 +1,35 @@
+mod config;
 mod create;
 mod delete;
+mod git;
+mod lock;
+mod manifest;
 mod status;
 mod tech;
+mod template;
 mod update;
 mod utils;
 
-use crate::create::{Component, Project, create_project, questionnaire};
+use crate::config::Config;
+use crate::create::{Component, Project, Tools, VarOverrides, create_project, questionnaire};
 use crate::delete::delete_project;
+use crate::lock::lock_project;
 use crate::status::project_status;
+use crate::tech::node::pm::PackageManager;
+use crate::tech::node::workspace::TaskRunner;
+use crate::tech::python::env::PyTool;
 use crate::update::update_project;
 
 use clap::Parser;
+use std::path::Path;
 
 /// Simple program to greet a person
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
-    /// mode
+    /// mode: `create`, `update`, `delete`, `status` or `lock` (refresh the
+    /// Python components' lock files of the project `--name` in `--path`, or
+    /// of the project at `--path` without `--name`)
     #[arg(short, long, default_value = "default")]
     mode: String,
 
@@ -36,26 +49,108 @@ struct Args {
     #[arg(short, long, default_value = "")]
     components: Vec<String>,
 
-    /// component tech
+    /// component tech, or `name=tech`; `tech@version` pins the framework, `:python=3.12` picks the Python (e.g. `web=django@5.1:python=3.12`, `api=template:git+file:///srv/api.git#v2`)
     #[arg(short, long, default_value = "")]
     tech: Vec<String>,
+
+    /// template/tech variable, `key=value` or `component.key=value`
+    #[arg(long = "var", value_name = "KEY=VALUE")]
+    vars: Vec<String>,
+
+    /// Python environment/dependency tool: `pip`, `uv`, `poetry`, `pdm`, `hatch`,
+    /// `conda` or `mamba` (default: `[python] tool` from the config file, else
+    /// conda for data-science packages and uv for the rest, if installed)
+    #[arg(long = "py-tool", value_name = "TOOL")]
+    py_tool: Option<String>,
+
+    /// Node.js package manager: `npm`, `yarn`, `pnpm` or `bun` (default:
+    /// `[node] pm` from the config file, else the first one installed)
+    #[arg(long = "pm", value_name = "PM")]
+    pm: Option<String>,
+
+    /// Task runner for the workspace of a project with several Node.js
+    /// components: `turbo` or `nx` (default: `[node] task_runner` from the
+    /// config file, else plain root scripts)
+    #[arg(long = "task-runner", value_name = "RUNNER")]
+    task_runner: Option<String>,
 }
 
 fn main() {
     let args = Args::parse();
+    let overrides = match VarOverrides::parse(&args.vars) {
+        Ok(overrides) => overrides,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return;
+        }
+    };
+    let config = Config::load();
+    let py_tool = args.py_tool.as_deref().or(config.python.tool.as_deref());
+    let python = match py_tool.map(|name| (name, PyTool::parse(name))) {
+        None => None,
+        Some((_, Some(tool))) => Some(tool),
+        Some((name, None)) => {
+            eprintln!(
+                "Error: unknown Python tool '{}', expected pip, uv, poetry, pdm, hatch, conda or mamba",
+                name
+            );
+            return;
+        }
+    };
+    let pm = args.pm.as_deref().or(config.node.pm.as_deref());
+    let node = match pm.map(|name| (name, PackageManager::parse(name))) {
+        None => None,
+        Some((_, Some(pm))) => Some(pm),
+        Some((name, None)) => {
+            eprintln!(
+                "Error: unknown package manager '{}', expected npm, yarn, pnpm or bun",
+                name
+            );
+            return;
+        }
+    };
+    let task_runner = args
+        .task_runner
+        .as_deref()
+        .or(config.node.task_runner.as_deref());
+    let task_runner = match task_runner.map(|name| (name, TaskRunner::parse(name))) {
+        None => None,
+        Some((_, Some(runner))) => Some(runner),
+        Some((name, None)) => {
+            eprintln!(
+                "Error: unknown task runner '{}', expected turbo or nx",
+                name
+            );
+            return;
+        }
+    };
+    let tools = Tools {
+        python,
+        node,
+        task_runner,
+    };
     let mut components = Vec::new();
 
-    for (component, tech) in args.components.iter().zip(args.tech.iter()) {
-        components.push(Component {
-            name: component.clone(),
-            tech: tech.clone(),
-        });
+    for (i, tech) in args.tech.iter().enumerate() {
+        if let Some(component) = Component::parse(tech) {
+            components.push(component);
+        } else if let Some(name) = args.components.get(i) {
+            components.push(Component {
+                name: name.clone(),
+                tech: tech.clone(),
+                vars: Default::default(),
+            });
+        }
+    }
+    for component in &mut components {
+        component.vars = overrides.for_component(&component.name);
     }
 
     let new_project = Project {
         name: args.name,
         path: args.path,
-        components: components,
+        components,
+        tools,
     };
 
     if args.mode == "default" {
@@ -66,7 +161,7 @@ fn main() {
                 eprintln!("Error creating project: {}", e);
             }
         } else {
-            if let Err(e) = questionnaire() {
+            if let Err(e) = questionnaire(&overrides, tools) {
                 eprintln!("Error in questionnaire: {}", e);
             }
         }
@@ -90,6 +185,17 @@ fn main() {
         }
     } else if args.mode == "status" {
         project_status(args.id);
+    } else if args.mode == "lock" {
+        // The project lives at `<path>/<name>`, as `create` puts it
+        let path = Path::new(&new_project.path);
+        let project_dir = if new_project.name != "Project" {
+            path.join(&new_project.name)
+        } else {
+            path.to_path_buf()
+        };
+        if let Err(e) = lock_project(&project_dir) {
+            eprintln!("Error locking project: {}", e);
+        }
     } else {
         println!("Invalid mode!");
     }
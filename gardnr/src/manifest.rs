@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+pub const MANIFEST_FILE: &str = "gardnr.toml";
+
+/// Record of how a project and its components were scaffolded, stored as
+/// `gardnr.toml` in the project root.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Manifest {
+    pub project: ProjectManifest,
+    #[serde(default)]
+    pub components: BTreeMap<String, ComponentManifest>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ProjectManifest {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ComponentManifest {
+    pub tech: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// Tool managing the component's Python environment (`pip`, `uv`, ...)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub py_tool: Option<String>,
+    /// Named conda env the component runs in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conda_env: Option<String>,
+    /// Package manager of the component's Node.js project (`npm`, `pnpm`, ...)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pm: Option<String>,
+    /// Answers given for the tech's or template's variables
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub vars: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    pub fn new(project_name: &str) -> Self {
+        Manifest {
+            project: ProjectManifest {
+                name: project_name.to_string(),
+            },
+            components: BTreeMap::new(),
+        }
+    }
+
+    pub fn load(project_dir: &Path) -> io::Result<Self> {
+        let content = fs::read_to_string(project_dir.join(MANIFEST_FILE))?;
+        toml::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, project_dir: &Path) -> io::Result<()> {
+        let content = toml::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(project_dir.join(MANIFEST_FILE), content)
+    }
+}
@@ -3,11 +3,76 @@ pub mod node;
 pub mod python;
 pub mod rust;
 
+use crate::template::vars::{TemplateVar, VarKind};
+use std::collections::BTreeMap;
+
+/// A component's tech as given on the command line, e.g. `django`,
+/// `react@18` or `fastapi@0.115:python=3.12`.
+pub struct TechSpec {
+    pub name: String,
+    /// Version selector after the `@`, e.g. `5.1` or `>=4.2,<5`
+    pub version: Option<String>,
+    /// `:key=value` options after the tech; a bare `:key` means `key=true`
+    pub options: BTreeMap<String, String>,
+}
+
+impl TechSpec {
+    pub fn parse(spec: &str) -> TechSpec {
+        let mut parts = spec.split(':');
+        let tech = parts.next().unwrap_or_default();
+        let options = parts
+            .filter(|option| !option.is_empty())
+            .map(|option| match option.split_once('=') {
+                Some((key, value)) => (key.to_string(), value.to_string()),
+                None => (option.to_string(), "true".to_string()),
+            })
+            .collect();
+
+        // A leading `@` belongs to a scoped npm package name, not a version
+        let (name, version) = match tech.rsplit_once('@') {
+            Some((name, version)) if !name.is_empty() && !version.is_empty() => {
+                (name, Some(version.to_string()))
+            }
+            _ => (tech, None),
+        };
+
+        TechSpec {
+            name: name.to_string(),
+            version,
+            options,
+        }
+    }
+
+    pub fn option(&self, key: &str) -> Option<&str> {
+        self.options.get(key).map(|value| value.as_str())
+    }
+}
+
+/// The `port` variable shared by every server-style scaffold.
+pub fn port_var(default: u16) -> TemplateVar {
+    TemplateVar::new("port", VarKind::Int, &default.to_string())
+        .help("Port to listen on")
+        .validator("{% if port < 1 or port > 65535 %}port must be between 1 and 65535{% endif %}")
+}
+
+/// The `title` variable of scaffolds that show a name in their code or UI.
+pub fn title_var() -> TemplateVar {
+    TemplateVar::new("title", VarKind::String, "{{ component }}").help("Title of the app")
+}
+
+/// The `cors_origins` variable of API scaffolds; `*` allows every origin.
+pub fn cors_var() -> TemplateVar {
+    TemplateVar::new("cors_origins", VarKind::List, "*")
+        .help("Origins allowed to call the API, comma-separated")
+}
+
+#[allow(dead_code)]
 pub struct Tech {
     pub name: String,
     pub aliases: Vec<String>,
 }
 
+#[allow(dead_code)]
 impl Tech {
     pub fn from_name(name: &str) -> Option<Tech> {
         match name.to_lowercase().as_str() {
@@ -51,3 +116,48 @@ impl Tech {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plain_tech() {
+        let spec = TechSpec::parse("django");
+        assert_eq!(spec.name, "django");
+        assert_eq!(spec.version, None);
+        assert!(spec.options.is_empty());
+    }
+
+    #[test]
+    fn parses_exact_and_partial_versions() {
+        assert_eq!(
+            TechSpec::parse("django@5.1.4").version.as_deref(),
+            Some("5.1.4")
+        );
+        assert_eq!(
+            TechSpec::parse("django@5.1").version.as_deref(),
+            Some("5.1")
+        );
+    }
+
+    #[test]
+    fn parses_range_version_with_options() {
+        let spec = TechSpec::parse("django@>=4.2,<5:python=3.12:drf");
+        assert_eq!(spec.name, "django");
+        assert_eq!(spec.version.as_deref(), Some(">=4.2,<5"));
+        assert_eq!(spec.option("python"), Some("3.12"));
+        assert_eq!(spec.option("drf"), Some("true"));
+    }
+
+    #[test]
+    fn leading_at_is_a_scoped_package() {
+        let spec = TechSpec::parse("@angular/core");
+        assert_eq!(spec.name, "@angular/core");
+        assert_eq!(spec.version, None);
+
+        let spec = TechSpec::parse("@angular/core@19");
+        assert_eq!(spec.name, "@angular/core");
+        assert_eq!(spec.version.as_deref(), Some("19"));
+    }
+}
@@ -0,0 +1,1120 @@
+pub mod package_json;
+pub mod pm;
+pub mod workspace;
+
+use super::{TechSpec, cors_var, port_var};
+use crate::template::vars::{self, TemplateVar, VarKind};
+use crate::template::{self, TemplateContext};
+use package_json::{PackageJson, package_name};
+use pm::{PackageManager, run_in};
+use std::{collections::BTreeMap, fs, io, path::Path, process::Command};
+
+// Versions of the create tools gardnr runs. They are pinned so that the same
+// command scaffolds the same project; bump them deliberately.
+const CREATE_VITE: &str = "vite@6.5.0";
+const CREATE_VUE: &str = "vue@3.16.0";
+const CREATE_NEXT_APP: &str = "next-app@15.3.3";
+const NUXI: &str = "nuxi@3.25.1";
+const CREATE_ASTRO: &str = "astro@4.11.0";
+const CREATE_REMIX: &str = "remix@2.16.8";
+const SV: &str = "sv@0.8.3";
+
+/// How a `JsApp` is created in `<project>/` before it is renamed.
+enum CreateTool {
+    /// `<pm> create <initializer> <dir> <args>`
+    Create(&'static str, &'static [&'static str]),
+    /// `<cli> new <name> --directory <dir> <args>`, run through `exec`; the CLI
+    /// is released with the framework, so a requested version selects it
+    New(&'static str, &'static str, &'static [&'static str]),
+}
+
+/// A frontend created by its own tool and then installed with the
+/// component's package manager.
+struct JsApp {
+    tech: &'static str,
+    name: &'static str,
+    create: CreateTool,
+    /// Environment for the create tool, to keep it from asking questions
+    env: &'static [(&'static str, &'static str)],
+    /// Dependencies pinned to a requested version (with `CreateTool::Create`)
+    packages: &'static [&'static str],
+    dev_packages: &'static [&'static str],
+    /// `dev` script added for tools that start their dev server differently
+    dev: Option<&'static str>,
+}
+
+const JS_APPS: &[JsApp] = &[
+    JsApp {
+        tech: "angular",
+        name: "Angular",
+        create: CreateTool::New(
+            "@angular/cli",
+            "20.0.0",
+            &["--defaults", "--ssr=false", "--skip-git", "--skip-install"],
+        ),
+        env: &[("NG_CLI_ANALYTICS", "false")],
+        packages: &[],
+        dev_packages: &[],
+        dev: Some("ng serve"),
+    },
+    JsApp {
+        tech: "svelte",
+        name: "Svelte",
+        create: CreateTool::Create(CREATE_VITE, &["--template", "svelte-ts"]),
+        env: &[],
+        packages: &[],
+        dev_packages: &["svelte"],
+        dev: None,
+    },
+    JsApp {
+        tech: "solid",
+        name: "SolidJS",
+        create: CreateTool::Create(CREATE_VITE, &["--template", "solid-ts"]),
+        env: &[],
+        packages: &["solid-js"],
+        dev_packages: &[],
+        dev: None,
+    },
+    JsApp {
+        tech: "preact",
+        name: "Preact",
+        create: CreateTool::Create(CREATE_VITE, &["--template", "preact-ts"]),
+        env: &[],
+        packages: &["preact"],
+        dev_packages: &[],
+        dev: None,
+    },
+    JsApp {
+        tech: "qwik",
+        name: "Qwik",
+        create: CreateTool::Create(CREATE_VITE, &["--template", "qwik-ts"]),
+        env: &[],
+        packages: &[],
+        dev_packages: &["@builder.io/qwik"],
+        dev: None,
+    },
+    JsApp {
+        tech: "astro",
+        name: "Astro",
+        create: CreateTool::Create(
+            CREATE_ASTRO,
+            &[
+                "--template",
+                "minimal",
+                "--no-install",
+                "--no-git",
+                "--skip-houston",
+                "--yes",
+            ],
+        ),
+        env: &[],
+        packages: &["astro"],
+        dev_packages: &[],
+        dev: None,
+    },
+    JsApp {
+        tech: "remix",
+        name: "Remix",
+        create: CreateTool::Create(CREATE_REMIX, &["--yes", "--no-install", "--no-git-init"]),
+        env: &[],
+        packages: &["@remix-run/node", "@remix-run/react", "@remix-run/serve"],
+        dev_packages: &["@remix-run/dev"],
+        dev: None,
+    },
+    JsApp {
+        tech: "ember",
+        name: "Ember",
+        create: CreateTool::New(
+            "ember-cli",
+            "6.4.0",
+            &["--typescript", "--skip-git", "--skip-npm"],
+        ),
+        env: &[],
+        packages: &[],
+        dev_packages: &[],
+        dev: Some("ember serve"),
+    },
+];
+
+fn js_app(tech: &str) -> Option<&'static JsApp> {
+    let tech = match tech {
+        "solidjs" => "solid",
+        "emberjs" => "ember",
+        tech => tech,
+    };
+    JS_APPS.iter().find(|app| app.tech == tech)
+}
+
+/// Variables the scaffold for `tech` can be customised with.
+pub fn variables(tech: &str) -> Vec<TemplateVar> {
+    match tech.to_lowercase().as_str() {
+        "node" | "nodejs" | "express" => vec![
+            port_var(3000),
+            cors_var(),
+            TemplateVar::new("typescript", VarKind::Bool, "false")
+                .help("Write the server in TypeScript?"),
+        ],
+        "react" | "vue" => vec![tailwind_var(false)],
+        "nextjs" | "next" => vec![tailwind_var(true)],
+        _ => Vec::new(),
+    }
+}
+
+fn tailwind_var(default: bool) -> TemplateVar {
+    TemplateVar::new("tailwind", VarKind::Bool, &default.to_string()).help("Use Tailwind CSS?")
+}
+
+/// Scaffold a Node.js component, returning the package manager it was set
+/// up with; `None` if there was none to use.
+pub fn scaffold_js_project(
+    project_dir: &Path,
+    component_name: &str,
+    spec: &TechSpec,
+    pm: Option<PackageManager>,
+    answers: &BTreeMap<String, String>,
+) -> io::Result<Option<PackageManager>> {
+    let tech = spec.name.as_str();
+    let version = spec.version.as_deref();
+    let component_dir = project_dir.join(component_name);
+
+    // Check if Node.js and a package manager are installed
+    let pm = match PackageManager::resolve(pm) {
+        Some(pm) => {
+            println!("→ Found Node.js package manager: {}", pm.command());
+            pm
+        }
+        None => {
+            eprintln!("⚠️  Node.js/npm not found! Creating basic directory structure only.");
+            eprintln!("   Install Node.js to enable full scaffolding.");
+            fs::create_dir_all(&component_dir)?;
+            return Ok(None);
+        }
+    };
+
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("my-app");
+    let mut context = TemplateContext::new(project_name, component_name);
+    let node_version = node_version();
+    if let Some(version) = &node_version {
+        context.set_version("node", version);
+    }
+    vars::resolve(&variables(tech), None, answers, false, &mut context)?;
+
+    match tech.to_lowercase().as_str() {
+        "react" => scaffold_react_with_vite(project_dir, component_name, pm, version, &context),
+        "vue" => scaffold_vue_with_vite(project_dir, component_name, pm, version, &context),
+        "sveltekit" | "svelte-kit" => scaffold_sveltekit(project_dir, component_name, pm, version),
+        "node" | "nodejs" => scaffold_node_express(&component_dir, pm, None, &context),
+        "express" => scaffold_node_express(&component_dir, pm, version, &context),
+        "nextjs" | "next" => scaffold_nextjs(project_dir, component_name, pm, version, &context),
+        "nuxt" => scaffold_nuxt(project_dir, component_name, pm, version),
+        tech if let Some(app) = js_app(tech) => {
+            scaffold_js_app(project_dir, component_name, pm, app, version)
+        }
+        _ => scaffold_generic_node(&component_dir, pm, tech, version),
+    }?;
+
+    update_package_json(&component_dir, &context, node_version.as_deref())?;
+    Ok(Some(pm))
+}
+
+fn node_version() -> Option<String> {
+    let output = Command::new("node").arg("--version").output().ok()?;
+    let version = String::from_utf8_lossy(&output.stdout);
+    version
+        .trim()
+        .strip_prefix('v')
+        .map(|version| version.to_string())
+}
+
+fn scaffold_react_with_vite(
+    project_dir: &Path,
+    component_name: &str,
+    pm: PackageManager,
+    version: Option<&str>,
+    context: &TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding React application with Vite");
+
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("my-react-app");
+
+    let create = pm.create(CREATE_VITE, &[project_name, "--template", "react-ts"]);
+    println!(
+        "→ Running {} in {}",
+        create.join(" "),
+        project_dir.display()
+    );
+
+    let status = run_in(&create, project_dir)?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "{} create vite failed for React",
+            pm.command()
+        )));
+    }
+
+    // Rename the Vite project directory to the component name
+    let vite_project_dir = project_dir.join(project_name);
+    let component_dir = project_dir.join(component_name);
+
+    if vite_project_dir.exists() && vite_project_dir != component_dir {
+        std::fs::rename(&vite_project_dir, &component_dir)?;
+        println!(
+            "→ Renamed React project directory from '{}' to '{}'",
+            project_name, component_name
+        );
+    }
+
+    // Install dependencies
+    println!("→ Installing dependencies...");
+    let install_status = run_in(&pm.install(), &component_dir)?;
+
+    if !install_status.success() {
+        eprintln!("⚠️  Failed to install dependencies, but project structure is ready");
+    }
+
+    if let Some(version) = version {
+        pin_packages(&component_dir, pm, &["react", "react-dom"], version, false)?;
+        pin_packages(
+            &component_dir,
+            pm,
+            &["@types/react", "@types/react-dom"],
+            version,
+            true,
+        )?;
+    }
+
+    if context.is_enabled("tailwind") {
+        add_tailwind_to_vite(&component_dir, pm, "src/index.css")?;
+    }
+
+    println!(
+        "→ React + TypeScript project '{}' scaffolded successfully as '{}'",
+        project_name, component_name
+    );
+    println!(
+        "   - Run: cd {} && {}",
+        component_name,
+        pm.run("dev").join(" ")
+    );
+    println!(
+        "   - Build: cd {} && {}",
+        component_name,
+        pm.run("build").join(" ")
+    );
+
+    Ok(())
+}
+
+fn scaffold_vue_with_vite(
+    project_dir: &Path,
+    component_name: &str,
+    pm: PackageManager,
+    version: Option<&str>,
+    context: &TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding Vue.js application with Vite");
+
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("my-vue-app");
+
+    // Create Vue project with recommended settings (non-interactive)
+    let create = pm.create(
+        CREATE_VUE,
+        &[
+            project_name,
+            "--typescript",
+            "--router",
+            "--pinia",
+            "--vitest",
+            "--eslint",
+        ],
+    );
+    println!(
+        "→ Running {} in {}",
+        create.join(" "),
+        project_dir.display()
+    );
+
+    let status = run_in(&create, project_dir)?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "{} create vue failed",
+            pm.command()
+        )));
+    }
+
+    // Rename the Vue project directory to the component name
+    let vue_project_dir = project_dir.join(project_name);
+    let component_dir = project_dir.join(component_name);
+
+    if vue_project_dir.exists() && vue_project_dir != component_dir {
+        std::fs::rename(&vue_project_dir, &component_dir)?;
+        println!(
+            "→ Renamed Vue project directory from '{}' to '{}'",
+            project_name, component_name
+        );
+    }
+
+    // Install dependencies
+    println!("→ Installing dependencies...");
+    let install_status = run_in(&pm.install(), &component_dir)?;
+
+    if !install_status.success() {
+        eprintln!("⚠️  Failed to install dependencies, but project structure is ready");
+    }
+
+    if let Some(version) = version {
+        pin_packages(&component_dir, pm, &["vue"], version, false)?;
+    }
+
+    if context.is_enabled("tailwind") {
+        add_tailwind_to_vite(&component_dir, pm, "src/assets/main.css")?;
+    }
+
+    println!(
+        "→ Vue.js project '{}' scaffolded successfully as '{}'",
+        project_name, component_name
+    );
+    println!(
+        "   - Run: cd {} && {}",
+        component_name,
+        pm.run("dev").join(" ")
+    );
+    println!(
+        "   - Build: cd {} && {}",
+        component_name,
+        pm.run("build").join(" ")
+    );
+
+    Ok(())
+}
+
+/// SvelteKit through `sv create`, with its add-ons applied by `sv add`;
+/// both are told everything they would otherwise ask.
+fn scaffold_sveltekit(
+    project_dir: &Path,
+    component_name: &str,
+    pm: PackageManager,
+    version: Option<&str>,
+) -> io::Result<()> {
+    println!("→ Scaffolding SvelteKit application");
+
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("my-sveltekit-app");
+
+    let create = pm.exec(
+        SV,
+        &[
+            "create",
+            project_name,
+            "--template",
+            "minimal",
+            "--types",
+            "ts",
+            "--no-add-ons",
+            "--no-install",
+        ],
+    );
+    println!(
+        "→ Running {} in {}",
+        create.join(" "),
+        project_dir.display()
+    );
+
+    let status = run_in(&create, project_dir)?;
+
+    if !status.success() {
+        return Err(io::Error::other("sv create failed"));
+    }
+
+    // Rename the SvelteKit project directory to the component name
+    let svelte_project_dir = project_dir.join(project_name);
+    let component_dir = project_dir.join(component_name);
+
+    if svelte_project_dir.exists() && svelte_project_dir != component_dir {
+        std::fs::rename(&svelte_project_dir, &component_dir)?;
+        println!(
+            "→ Renamed SvelteKit project directory from '{}' to '{}'",
+            project_name, component_name
+        );
+    }
+
+    let add = pm.exec(
+        SV,
+        &[
+            "add",
+            "prettier",
+            "eslint",
+            "--no-git-check",
+            "--no-install",
+        ],
+    );
+    println!("→ Running {}", add.join(" "));
+    let add_status = run_in(&add, &component_dir)?;
+
+    if !add_status.success() {
+        eprintln!("⚠️  Failed to add Prettier and ESLint");
+    }
+
+    // Install dependencies
+    println!("→ Installing dependencies...");
+    let install_status = run_in(&pm.install(), &component_dir)?;
+
+    if !install_status.success() {
+        eprintln!("⚠️  Failed to install dependencies, but project structure is ready");
+    }
+
+    if let Some(version) = version {
+        pin_packages(&component_dir, pm, &["@sveltejs/kit"], version, true)?;
+    }
+
+    println!(
+        "→ SvelteKit project '{}' scaffolded successfully as '{}'",
+        project_name, component_name
+    );
+    println!(
+        "   - Run: cd {} && {}",
+        component_name,
+        pm.run("dev").join(" ")
+    );
+    println!(
+        "   - Build: cd {} && {}",
+        component_name,
+        pm.run("build").join(" ")
+    );
+
+    Ok(())
+}
+
+fn scaffold_js_app(
+    project_dir: &Path,
+    component_name: &str,
+    pm: PackageManager,
+    app: &JsApp,
+    version: Option<&str>,
+) -> io::Result<()> {
+    println!("→ Scaffolding {} application", app.name);
+
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("my-app");
+
+    let create = match app.create {
+        CreateTool::Create(initializer, args) => {
+            let mut create_args = vec![project_name];
+            create_args.extend(args);
+            pm.create(initializer, &create_args)
+        }
+        CreateTool::New(cli, pinned, args) => {
+            let cli = format!("{}@{}", cli, version.unwrap_or(pinned));
+            // ng and ember only take kebab-case names
+            let name = package_name(component_name).replace(['_', '.'], "-");
+            let mut new_args = vec!["new", name.as_str(), "--directory", project_name];
+            new_args.extend(args);
+            pm.exec(&cli, &new_args)
+        }
+    };
+    println!(
+        "→ Running {} in {}",
+        create.join(" "),
+        project_dir.display()
+    );
+
+    let status = Command::new(&create[0])
+        .args(&create[1..])
+        .envs(app.env.iter().copied())
+        .current_dir(project_dir)
+        .status()?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "creating the {} project failed",
+            app.name
+        )));
+    }
+
+    // Rename the project directory to the component name
+    let app_project_dir = project_dir.join(project_name);
+    let component_dir = project_dir.join(component_name);
+
+    if app_project_dir.exists() && app_project_dir != component_dir {
+        std::fs::rename(&app_project_dir, &component_dir)?;
+        println!(
+            "→ Renamed {} project directory from '{}' to '{}'",
+            app.name, project_name, component_name
+        );
+    }
+
+    // Install dependencies
+    println!("→ Installing dependencies...");
+    let install_status = run_in(&pm.install(), &component_dir)?;
+
+    if !install_status.success() {
+        eprintln!("⚠️  Failed to install dependencies, but project structure is ready");
+    }
+
+    if let (Some(version), CreateTool::Create(..)) = (version, &app.create) {
+        if !app.packages.is_empty() {
+            pin_packages(&component_dir, pm, app.packages, version, false)?;
+        }
+        if !app.dev_packages.is_empty() {
+            pin_packages(&component_dir, pm, app.dev_packages, version, true)?;
+        }
+    }
+
+    if let Some(dev) = app.dev
+        && let Some(mut package) = PackageJson::open(&component_dir)?
+        && package.script("dev").is_none()
+    {
+        package.set_script("dev", dev).save()?;
+    }
+
+    println!(
+        "→ {} project '{}' scaffolded successfully as '{}'",
+        app.name, project_name, component_name
+    );
+    println!(
+        "   - Run: cd {} && {}",
+        component_name,
+        pm.run("dev").join(" ")
+    );
+    println!(
+        "   - Build: cd {} && {}",
+        component_name,
+        pm.run("build").join(" ")
+    );
+
+    Ok(())
+}
+
+fn scaffold_nextjs(
+    project_dir: &Path,
+    component_name: &str,
+    pm: PackageManager,
+    version: Option<&str>,
+    context: &TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding Next.js application");
+
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("my-next-app");
+
+    let tailwind_flag = if context.is_enabled("tailwind") {
+        "--tailwind"
+    } else {
+        "--no-tailwind"
+    };
+
+    // create-next-app is released in lockstep with Next.js, so a requested
+    // Next.js version selects the matching create tool
+    let create_next_app = match version {
+        Some(version) => format!("next-app@{}", version),
+        None => CREATE_NEXT_APP.to_string(),
+    };
+    // create-next-app installs the dependencies itself, with the manager it is told
+    let use_pm = format!("--use-{}", pm.command());
+
+    let create = pm.create(
+        &create_next_app,
+        &[
+            project_name,
+            "--typescript",
+            tailwind_flag,
+            "--eslint",
+            "--app",
+            "--src-dir",
+            "--import-alias",
+            "@/*",
+            &use_pm,
+        ],
+    );
+    println!(
+        "→ Running {} in {}",
+        create.join(" "),
+        project_dir.display()
+    );
+
+    let status = run_in(&create, project_dir)?;
+
+    if !status.success() {
+        return Err(io::Error::other("create-next-app failed"));
+    }
+
+    // Rename the Next.js project directory to the component name
+    let next_project_dir = project_dir.join(project_name);
+    let component_dir = project_dir.join(component_name);
+
+    if next_project_dir.exists() && next_project_dir != component_dir {
+        std::fs::rename(&next_project_dir, &component_dir)?;
+        println!(
+            "→ Renamed Next.js project directory from '{}' to '{}'",
+            project_name, component_name
+        );
+    }
+
+    println!(
+        "→ Next.js project '{}' scaffolded successfully as '{}'",
+        project_name, component_name
+    );
+    println!(
+        "   - Run: cd {} && {}",
+        component_name,
+        pm.run("dev").join(" ")
+    );
+    println!(
+        "   - Build: cd {} && {}",
+        component_name,
+        pm.run("build").join(" ")
+    );
+
+    Ok(())
+}
+
+fn scaffold_nuxt(
+    project_dir: &Path,
+    component_name: &str,
+    pm: PackageManager,
+    version: Option<&str>,
+) -> io::Result<()> {
+    println!("→ Scaffolding Nuxt.js application");
+
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("my-nuxt-app");
+
+    let init = pm.exec(
+        NUXI,
+        &["init", project_name, "--packageManager", pm.command()],
+    );
+    println!("→ Running {} in {}", init.join(" "), project_dir.display());
+
+    let status = run_in(&init, project_dir)?;
+
+    if !status.success() {
+        return Err(io::Error::other("nuxi init failed"));
+    }
+
+    // Rename the Nuxt project directory to the component name
+    let nuxt_project_dir = project_dir.join(project_name);
+    let component_dir = project_dir.join(component_name);
+
+    if nuxt_project_dir.exists() && nuxt_project_dir != component_dir {
+        std::fs::rename(&nuxt_project_dir, &component_dir)?;
+        println!(
+            "→ Renamed Nuxt project directory from '{}' to '{}'",
+            project_name, component_name
+        );
+    }
+
+    // Install dependencies
+    println!("→ Installing dependencies...");
+    let install_status = run_in(&pm.install(), &component_dir)?;
+
+    if !install_status.success() {
+        eprintln!("⚠️  Failed to install dependencies, but project structure is ready");
+    }
+
+    if let Some(version) = version {
+        pin_packages(&component_dir, pm, &["nuxt"], version, false)?;
+    }
+
+    println!(
+        "→ Nuxt.js project '{}' scaffolded successfully as '{}'",
+        project_name, component_name
+    );
+    println!(
+        "   - Run: cd {} && {}",
+        component_name,
+        pm.run("dev").join(" ")
+    );
+    println!(
+        "   - Build: cd {} && {}",
+        component_name,
+        pm.run("build").join(" ")
+    );
+
+    Ok(())
+}
+
+fn scaffold_node_express(
+    component_dir: &Path,
+    pm: PackageManager,
+    version: Option<&str>,
+    context: &TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding Node.js Express application");
+
+    fs::create_dir_all(component_dir)?;
+
+    // Initialize package.json
+    let status = run_in(&pm.init(), component_dir)?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!("{} init failed", pm.command())));
+    }
+
+    // Install Express
+    println!("→ Installing Express...");
+    let express = match version {
+        Some(version) => format!("express@{}", version),
+        None => "express".to_string(),
+    };
+    let install_status = run_in(
+        &pm.add(&[&express, "cors", "helmet", "dotenv"], false),
+        component_dir,
+    )?;
+
+    if !install_status.success() {
+        eprintln!("⚠️  Failed to install Express dependencies");
+    }
+
+    let typescript = context.is_enabled("typescript");
+    if typescript {
+        write_express_typescript(component_dir, pm, version, context)?;
+    } else {
+        // Install dev dependencies
+        let _dev_install = run_in(&pm.add_dev(&["nodemon"], false), component_dir);
+
+        // Create server.js
+        template::write(
+            "express",
+            "server.js",
+            &component_dir.join("server.js"),
+            context,
+        )?;
+
+        if let Some(mut package) = PackageJson::open(component_dir)? {
+            package
+                .set("main", "server.js")
+                .set_script("start", "node server.js")
+                .set_script("dev", "nodemon server.js")
+                .save()?;
+        }
+    }
+
+    // Create .env file
+    let env_file = component_dir.join(".env");
+    let env_content = format!(
+        "NODE_ENV=development\nPORT={}\n",
+        context
+            .get("port")
+            .map(|port| port.to_string())
+            .unwrap_or_default()
+    );
+    fs::write(env_file, env_content)?;
+
+    let component_name = component_dir.file_name().unwrap().to_str().unwrap();
+    println!("→ Express application scaffolded");
+    if typescript {
+        println!(
+            "   Files: src/server.ts, src/middleware/errors.ts, tsconfig.json, .env, package.json"
+        );
+        println!(
+            "   - Build: cd {} && {}",
+            component_name,
+            pm.run("build").join(" ")
+        );
+    } else {
+        println!("   Files: server.js, .env, package.json");
+    }
+    println!(
+        "   - Run: cd {} && {}",
+        component_name,
+        pm.run("dev").join(" ")
+    );
+
+    Ok(())
+}
+
+/// Write the Express server as an ES module in TypeScript: tsx restarts it
+/// in development, `tsc` compiles it to `dist/` for `start`.
+fn write_express_typescript(
+    component_dir: &Path,
+    pm: PackageManager,
+    version: Option<&str>,
+    context: &TemplateContext,
+) -> io::Result<()> {
+    // The types follow Express's major version, 4 and 5 differ
+    let express_types = match version.and_then(major_version) {
+        Some(major) => format!("@types/express@{}", major),
+        None => "@types/express".to_string(),
+    };
+    let _dev_install = run_in(
+        &pm.add_dev(
+            &[
+                "typescript",
+                "tsx",
+                "@types/node",
+                &express_types,
+                "@types/cors",
+            ],
+            false,
+        ),
+        component_dir,
+    );
+
+    let src_dir = component_dir.join("src");
+    fs::create_dir_all(src_dir.join("middleware"))?;
+    template::write("express", "server.ts", &src_dir.join("server.ts"), context)?;
+    template::write(
+        "express",
+        "errors.ts",
+        &src_dir.join("middleware").join("errors.ts"),
+        context,
+    )?;
+    template::write(
+        "express",
+        "tsconfig.json",
+        &component_dir.join("tsconfig.json"),
+        context,
+    )?;
+
+    if let Some(mut package) = PackageJson::open(component_dir)? {
+        package
+            .set("type", "module")
+            .set("main", "dist/server.js")
+            .set_script("dev", "tsx watch src/server.ts")
+            .set_script("build", "tsc")
+            .set_script("start", "node dist/server.js")
+            .set_script("typecheck", "tsc --noEmit")
+            .save()?;
+    }
+
+    Ok(())
+}
+
+/// Major version of an npm version or range: `5` for `5.1.0` or `^5.1`.
+fn major_version(version: &str) -> Option<&str> {
+    let version = version.trim_start_matches(['^', '~', '=', '>', 'v', ' ']);
+    let end = version
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(version.len());
+    (end > 0).then(|| &version[..end])
+}
+
+fn scaffold_generic_node(
+    component_dir: &Path,
+    pm: PackageManager,
+    tech: &str,
+    version: Option<&str>,
+) -> io::Result<()> {
+    println!("→ Scaffolding generic Node.js project for '{}'", tech);
+
+    fs::create_dir_all(component_dir)?;
+
+    // Initialize package.json
+    let status = run_in(&pm.init(), component_dir)?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!("{} init failed", pm.command())));
+    }
+
+    // Create index.js
+    let index_js = component_dir.join("index.js");
+    let index_code = format!(
+        r#"#!/usr/bin/env node
+
+/**
+ * {} - Node.js Application
+ */
+
+console.log('Hello from {}!');
+
+// Add your {} code here
+function main() {{
+    console.log('Starting {} application...');
+    // Your application logic here
+}}
+
+if (require.main === module) {{
+    main();
+}}
+
+module.exports = {{ main }};
+"#,
+        tech, tech, tech, tech
+    );
+
+    fs::write(index_js, index_code)?;
+
+    if let Some(mut package) = PackageJson::open(component_dir)? {
+        package
+            .set("main", "index.js")
+            .set_script("start", "node index.js")
+            .save()?;
+    }
+
+    // Try to install the package if it might be a Node.js package
+    if is_likely_node_package(tech) {
+        let package = match version {
+            Some(version) => format!("{}@{}", tech, version),
+            None => tech.to_string(),
+        };
+        println!("→ Attempting to install {} package...", package);
+        let _install_result = run_in(&pm.add(&[&package], false), component_dir);
+    }
+
+    println!("→ Generic Node.js project scaffolded");
+    println!("   Files: index.js, package.json");
+
+    Ok(())
+}
+
+fn is_likely_node_package(tech: &str) -> bool {
+    let node_packages = [
+        "express",
+        "koa",
+        "fastify",
+        "hapi",
+        "socket.io",
+        "ws",
+        "axios",
+        "lodash",
+        "moment",
+        "chalk",
+        "commander",
+        "inquirer",
+        "jest",
+        "mocha",
+        "webpack",
+        "rollup",
+        "eslint",
+        "prettier",
+        "typescript",
+        "babel",
+    ];
+
+    node_packages
+        .iter()
+        .any(|&pkg| tech.to_lowercase().contains(pkg))
+}
+
+/// Install `packages` at the requested `version` and record the exact
+/// resolved versions in package.json.
+fn pin_packages(
+    component_dir: &Path,
+    pm: PackageManager,
+    packages: &[&str],
+    version: &str,
+    dev: bool,
+) -> io::Result<()> {
+    let specs: Vec<String> = packages
+        .iter()
+        .map(|package| format!("{}@{}", package, version))
+        .collect();
+    println!("→ Pinning {}...", specs.join(" "));
+
+    let specs: Vec<&str> = specs.iter().map(String::as_str).collect();
+    let add = if dev {
+        pm.add_dev(&specs, true)
+    } else {
+        pm.add(&specs, true)
+    };
+    let status = run_in(&add, component_dir)?;
+
+    if !status.success() {
+        eprintln!("⚠️  Failed to install {}", specs.join(" "));
+    }
+
+    Ok(())
+}
+
+/// Wire Tailwind CSS into a Vite project through the `@tailwindcss/vite` plugin.
+fn add_tailwind_to_vite(
+    component_dir: &Path,
+    pm: PackageManager,
+    css_file: &str,
+) -> io::Result<()> {
+    println!("→ Adding Tailwind CSS...");
+    let install_status = run_in(
+        &pm.add_dev(&["tailwindcss", "@tailwindcss/vite"], false),
+        component_dir,
+    )?;
+
+    if !install_status.success() {
+        eprintln!("⚠️  Failed to install Tailwind CSS");
+        return Ok(());
+    }
+
+    let config_path = ["vite.config.ts", "vite.config.js", "vite.config.mts"]
+        .iter()
+        .map(|name| component_dir.join(name))
+        .find(|path| path.exists());
+    match config_path {
+        Some(config_path) => {
+            let config = fs::read_to_string(&config_path)?;
+            let config = format!(
+                "import tailwindcss from '@tailwindcss/vite'\n{}",
+                config.replacen("plugins: [", "plugins: [tailwindcss(), ", 1)
+            );
+            fs::write(config_path, config)?;
+        }
+        None => eprintln!("⚠️  No vite.config found, add the Tailwind plugin manually"),
+    }
+
+    let css_path = component_dir.join(css_file);
+    let css = fs::read_to_string(&css_path).unwrap_or_default();
+    fs::write(css_path, format!("@import \"tailwindcss\";\n\n{}", css))?;
+
+    Ok(())
+}
+
+/// Name and describe the component in its `package.json`, whichever tool
+/// created it, and record the Node.js major version it was scaffolded with.
+fn update_package_json(
+    component_dir: &Path,
+    context: &TemplateContext,
+    node_version: Option<&str>,
+) -> io::Result<()> {
+    let Some(mut package) = PackageJson::open(component_dir)? else {
+        return Ok(());
+    };
+    let var = |key: &str| {
+        context
+            .get(key)
+            .and_then(|value| value.as_str())
+            .unwrap_or_default()
+    };
+
+    package
+        .set("name", package_name(var("component")))
+        .set_default(
+            "description",
+            &format!("{} component of {}", var("component"), var("project")),
+        )
+        .set_default("author", var("author"));
+    if let Some(major) = node_version.and_then(|version| version.split('.').next()) {
+        package.set_engine("node", &format!(">={}", major));
+    }
+    package.save()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn major_version_of_versions_and_ranges() {
+        assert_eq!(major_version("5"), Some("5"));
+        assert_eq!(major_version("4.21.2"), Some("4"));
+        assert_eq!(major_version("^5.1"), Some("5"));
+        assert_eq!(major_version(">=4 <5"), Some("4"));
+        assert_eq!(major_version("latest"), None);
+    }
+}
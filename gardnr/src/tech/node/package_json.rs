@@ -0,0 +1,124 @@
+use serde_json::{Map, Value as Json};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const PACKAGE_JSON: &str = "package.json";
+
+/// A `package.json` edited as JSON. Keys keep their order, so the file reads
+/// the same as before apart from the fields that were set.
+pub struct PackageJson {
+    path: PathBuf,
+    fields: Map<String, Json>,
+}
+
+impl PackageJson {
+    /// Read the `package.json` in `dir`, `None` if there is none.
+    pub fn open(dir: &Path) -> io::Result<Option<PackageJson>> {
+        let path = dir.join(PACKAGE_JSON);
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let fields = serde_json::from_str(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })?;
+        Ok(Some(PackageJson { path, fields }))
+    }
+
+    /// An empty `package.json` in `dir`, written on `save`.
+    pub fn new(dir: &Path) -> PackageJson {
+        PackageJson {
+            path: dir.join(PACKAGE_JSON),
+            fields: Map::new(),
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.fields.get("name").and_then(|name| name.as_str())
+    }
+
+    /// Command of `scripts.<name>`.
+    pub fn script(&self, name: &str) -> Option<&str> {
+        self.fields
+            .get("scripts")
+            .and_then(|scripts| scripts.get(name))
+            .and_then(|script| script.as_str())
+    }
+
+    /// Set a top-level field, in place if it exists, else at the end.
+    pub fn set(&mut self, key: &str, value: impl Into<Json>) -> &mut Self {
+        self.fields.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Set a top-level string field unless it already has a non-empty value.
+    pub fn set_default(&mut self, key: &str, value: &str) -> &mut Self {
+        let is_empty = match self.fields.get(key) {
+            None | Some(Json::Null) => true,
+            Some(Json::String(current)) => current.is_empty(),
+            Some(_) => false,
+        };
+        if is_empty && !value.is_empty() {
+            self.set(key, value);
+        }
+        self
+    }
+
+    /// Set `scripts.<name>`.
+    pub fn set_script(&mut self, name: &str, command: &str) -> &mut Self {
+        self.set_nested("scripts", name, command)
+    }
+
+    /// Set the version range of `engines.<name>`, e.g. `node`.
+    pub fn set_engine(&mut self, name: &str, range: &str) -> &mut Self {
+        self.set_nested("engines", name, range)
+    }
+
+    /// Set the version range of `devDependencies.<name>`.
+    pub fn set_dev_dependency(&mut self, name: &str, range: &str) -> &mut Self {
+        self.set_nested("devDependencies", name, range)
+    }
+
+    fn set_nested(&mut self, section: &str, key: &str, value: &str) -> &mut Self {
+        let section = self
+            .fields
+            .entry(section)
+            .or_insert_with(|| Json::Object(Map::new()));
+        if !section.is_object() {
+            *section = Json::Object(Map::new());
+        }
+        if let Json::Object(section) = section {
+            section.insert(key.to_string(), Json::from(value));
+        }
+        self
+    }
+
+    /// Write the file back, indented by two spaces like npm does.
+    pub fn save(&self) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(&self.fields).map_err(io::Error::other)?;
+        fs::write(&self.path, format!("{}\n", content))
+    }
+}
+
+/// A valid npm package name for `name`: lower case, with anything npm does
+/// not allow replaced by `-`.
+pub fn package_name(name: &str) -> String {
+    let name: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    name.trim_start_matches(['.', '_']).to_string()
+}
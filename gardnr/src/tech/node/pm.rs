@@ -0,0 +1,388 @@
+use crate::utils::is_installed;
+use std::{
+    io,
+    path::Path,
+    process::{Command, ExitStatus},
+};
+
+/// The package manager a JavaScript component is created and installed with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PackageManager {
+    Npm,
+    /// Yarn 1.x
+    Yarn,
+    /// Yarn 2 and later, which has `dlx` instead of relying on `npx`
+    YarnBerry,
+    Pnpm,
+    Bun,
+}
+
+impl PackageManager {
+    /// A package manager by name; `yarn` is told apart from Yarn Berry by the
+    /// installed version.
+    pub fn parse(name: &str) -> Option<PackageManager> {
+        match name.to_lowercase().as_str() {
+            "npm" => Some(PackageManager::Npm),
+            "yarn" => Some(PackageManager::yarn()),
+            "pnpm" => Some(PackageManager::Pnpm),
+            "bun" => Some(PackageManager::Bun),
+            _ => None,
+        }
+    }
+
+    /// Executable of the package manager, also the name `parse` accepts.
+    pub fn command(self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm",
+            PackageManager::Yarn | PackageManager::YarnBerry => "yarn",
+            PackageManager::Pnpm => "pnpm",
+            PackageManager::Bun => "bun",
+        }
+    }
+
+    /// The first installed of npm, yarn, pnpm and bun.
+    pub fn detect() -> Option<PackageManager> {
+        let command = is_installed(&["npm", "yarn", "pnpm", "bun"])?;
+        PackageManager::parse(&command)
+    }
+
+    /// `preferred` if it is installed, otherwise whatever `detect` finds.
+    pub fn resolve(preferred: Option<PackageManager>) -> Option<PackageManager> {
+        match preferred {
+            Some(pm) if is_installed(&[pm.command()]).is_some() => Some(pm),
+            Some(pm) => {
+                eprintln!(
+                    "⚠️  {} not found, looking for another package manager",
+                    pm.command()
+                );
+                PackageManager::detect()
+            }
+            None => PackageManager::detect(),
+        }
+    }
+
+    /// Yarn Berry if `yarn --version` reports 2 or later, classic Yarn
+    /// otherwise (including when it cannot be run).
+    fn yarn() -> PackageManager {
+        let major =
+            version_of("yarn").and_then(|version| version.split('.').next()?.parse::<u32>().ok());
+        match major {
+            Some(major) if major >= 2 => PackageManager::YarnBerry,
+            _ => PackageManager::Yarn,
+        }
+    }
+
+    /// Installed version of the package manager, as `packageManager` in
+    /// `package.json` wants it: `pnpm@9.15.0`.
+    pub fn version(self) -> Option<String> {
+        version_of(self.command()).map(|version| format!("{}@{}", self.command(), version))
+    }
+
+    /// Run the `create-<initializer>` package, e.g. `vite@6.5.0` for
+    /// `create-vite@6.5.0`, passing it `args`.
+    pub fn create(self, initializer: &str, args: &[&str]) -> Vec<String> {
+        let mut argv = self.argv(&["create", initializer]);
+        // npm would take the initializer's flags for its own without `--`
+        if self == PackageManager::Npm {
+            argv.push("--".to_string());
+        }
+        argv.extend(args.iter().map(|arg| arg.to_string()));
+        argv
+    }
+
+    /// Create a `package.json` without asking questions.
+    pub fn init(self) -> Vec<String> {
+        match self {
+            // Berry's init is non-interactive and rejects `-y`
+            PackageManager::YarnBerry => self.argv(&["init"]),
+            PackageManager::Pnpm => self.argv(&["init"]),
+            _ => self.argv(&["init", "-y"]),
+        }
+    }
+
+    /// Install the dependencies listed in `package.json`.
+    pub fn install(self) -> Vec<String> {
+        self.argv(&["install"])
+    }
+
+    /// Add `packages` to the dependencies, at exactly the resolved version if
+    /// `exact` is set.
+    pub fn add(self, packages: &[&str], exact: bool) -> Vec<String> {
+        self.add_packages(packages, false, exact)
+    }
+
+    /// Add `packages` to the dev dependencies, at exactly the resolved version
+    /// if `exact` is set.
+    pub fn add_dev(self, packages: &[&str], exact: bool) -> Vec<String> {
+        self.add_packages(packages, true, exact)
+    }
+
+    fn add_packages(self, packages: &[&str], dev: bool, exact: bool) -> Vec<String> {
+        let mut argv = match self {
+            PackageManager::Npm => self.argv(&["install"]),
+            _ => self.argv(&["add"]),
+        };
+        if dev {
+            argv.push(
+                match self {
+                    PackageManager::Npm => "--save-dev",
+                    PackageManager::Bun => "--dev",
+                    _ => "-D",
+                }
+                .to_string(),
+            );
+        }
+        if exact {
+            argv.push(
+                match self {
+                    PackageManager::Npm | PackageManager::Pnpm => "--save-exact",
+                    _ => "--exact",
+                }
+                .to_string(),
+            );
+        }
+        argv.extend(packages.iter().map(|package| package.to_string()));
+        argv
+    }
+
+    /// Run a `package.json` script.
+    pub fn run(self, script: &str) -> Vec<String> {
+        match self {
+            PackageManager::Yarn | PackageManager::YarnBerry | PackageManager::Pnpm => {
+                self.argv(&[script])
+            }
+            _ => self.argv(&["run", script]),
+        }
+    }
+
+    /// Run a script of the workspace package named `workspace` from the
+    /// workspace root.
+    pub fn run_workspace(self, workspace: &str, script: &str) -> Vec<String> {
+        match self {
+            PackageManager::Npm => self.argv(&["run", script, "--workspace", workspace]),
+            PackageManager::Yarn | PackageManager::YarnBerry => {
+                self.argv(&["workspace", workspace, "run", script])
+            }
+            PackageManager::Pnpm => self.argv(&["--filter", workspace, "run", script]),
+            PackageManager::Bun => self.argv(&["run", "--filter", workspace, script]),
+        }
+    }
+
+    /// Download `package` and run its binary with `args`, without adding it
+    /// to the project.
+    pub fn exec(self, package: &str, args: &[&str]) -> Vec<String> {
+        let mut argv: Vec<String> = match self {
+            // Classic Yarn has no `dlx`, npx comes with Node.js
+            PackageManager::Npm | PackageManager::Yarn => {
+                vec!["npx".to_string(), "--yes".to_string(), package.to_string()]
+            }
+            PackageManager::YarnBerry => self.argv(&["dlx", package]),
+            PackageManager::Pnpm => self.argv(&["dlx", package]),
+            PackageManager::Bun => vec!["bunx".to_string(), package.to_string()],
+        };
+        argv.extend(args.iter().map(|arg| arg.to_string()));
+        argv
+    }
+
+    fn argv(self, args: &[&str]) -> Vec<String> {
+        let mut argv = vec![self.command().to_string()];
+        argv.extend(args.iter().map(|arg| arg.to_string()));
+        argv
+    }
+}
+
+fn version_of(command: &str) -> Option<String> {
+    let output = Command::new(command).arg("--version").output().ok()?;
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !version.is_empty()).then_some(version)
+}
+
+/// Run a command line built by `PackageManager` in `dir`.
+pub fn run_in(argv: &[String], dir: &Path) -> io::Result<ExitStatus> {
+    Command::new(&argv[0])
+        .args(&argv[1..])
+        .current_dir(dir)
+        .status()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PackageManager::*;
+
+    const ALL: [PackageManager; 5] = [Npm, Yarn, YarnBerry, Pnpm, Bun];
+
+    fn expect(build: impl Fn(PackageManager) -> Vec<String>, expected: [&[&str]; 5]) {
+        for (pm, expected) in ALL.into_iter().zip(expected) {
+            assert_eq!(build(pm), expected, "{:?}", pm);
+        }
+    }
+
+    #[test]
+    fn create() {
+        expect(
+            |pm| pm.create("vite@6.5.0", &["app", "--template", "react-ts"]),
+            [
+                &[
+                    "npm",
+                    "create",
+                    "vite@6.5.0",
+                    "--",
+                    "app",
+                    "--template",
+                    "react-ts",
+                ],
+                &[
+                    "yarn",
+                    "create",
+                    "vite@6.5.0",
+                    "app",
+                    "--template",
+                    "react-ts",
+                ],
+                &[
+                    "yarn",
+                    "create",
+                    "vite@6.5.0",
+                    "app",
+                    "--template",
+                    "react-ts",
+                ],
+                &[
+                    "pnpm",
+                    "create",
+                    "vite@6.5.0",
+                    "app",
+                    "--template",
+                    "react-ts",
+                ],
+                &[
+                    "bun",
+                    "create",
+                    "vite@6.5.0",
+                    "app",
+                    "--template",
+                    "react-ts",
+                ],
+            ],
+        );
+    }
+
+    #[test]
+    fn init() {
+        expect(
+            PackageManager::init,
+            [
+                &["npm", "init", "-y"],
+                &["yarn", "init", "-y"],
+                &["yarn", "init"],
+                &["pnpm", "init"],
+                &["bun", "init", "-y"],
+            ],
+        );
+    }
+
+    #[test]
+    fn install() {
+        expect(
+            PackageManager::install,
+            [
+                &["npm", "install"],
+                &["yarn", "install"],
+                &["yarn", "install"],
+                &["pnpm", "install"],
+                &["bun", "install"],
+            ],
+        );
+    }
+
+    #[test]
+    fn add() {
+        expect(
+            |pm| pm.add(&["express@5", "cors"], false),
+            [
+                &["npm", "install", "express@5", "cors"],
+                &["yarn", "add", "express@5", "cors"],
+                &["yarn", "add", "express@5", "cors"],
+                &["pnpm", "add", "express@5", "cors"],
+                &["bun", "add", "express@5", "cors"],
+            ],
+        );
+        expect(
+            |pm| pm.add(&["express"], true),
+            [
+                &["npm", "install", "--save-exact", "express"],
+                &["yarn", "add", "--exact", "express"],
+                &["yarn", "add", "--exact", "express"],
+                &["pnpm", "add", "--save-exact", "express"],
+                &["bun", "add", "--exact", "express"],
+            ],
+        );
+    }
+
+    #[test]
+    fn add_dev() {
+        expect(
+            |pm| pm.add_dev(&["typescript"], false),
+            [
+                &["npm", "install", "--save-dev", "typescript"],
+                &["yarn", "add", "-D", "typescript"],
+                &["yarn", "add", "-D", "typescript"],
+                &["pnpm", "add", "-D", "typescript"],
+                &["bun", "add", "--dev", "typescript"],
+            ],
+        );
+        expect(
+            |pm| pm.add_dev(&["typescript"], true),
+            [
+                &["npm", "install", "--save-dev", "--save-exact", "typescript"],
+                &["yarn", "add", "-D", "--exact", "typescript"],
+                &["yarn", "add", "-D", "--exact", "typescript"],
+                &["pnpm", "add", "-D", "--save-exact", "typescript"],
+                &["bun", "add", "--dev", "--exact", "typescript"],
+            ],
+        );
+    }
+
+    #[test]
+    fn run() {
+        expect(
+            |pm| pm.run("dev"),
+            [
+                &["npm", "run", "dev"],
+                &["yarn", "dev"],
+                &["yarn", "dev"],
+                &["pnpm", "dev"],
+                &["bun", "run", "dev"],
+            ],
+        );
+    }
+
+    #[test]
+    fn run_workspace() {
+        expect(
+            |pm| pm.run_workspace("web", "build"),
+            [
+                &["npm", "run", "build", "--workspace", "web"],
+                &["yarn", "workspace", "web", "run", "build"],
+                &["yarn", "workspace", "web", "run", "build"],
+                &["pnpm", "--filter", "web", "run", "build"],
+                &["bun", "run", "--filter", "web", "build"],
+            ],
+        );
+    }
+
+    #[test]
+    fn exec() {
+        expect(
+            |pm| pm.exec("@angular/cli@19", &["new", "web"]),
+            [
+                &["npx", "--yes", "@angular/cli@19", "new", "web"],
+                &["npx", "--yes", "@angular/cli@19", "new", "web"],
+                &["yarn", "dlx", "@angular/cli@19", "new", "web"],
+                &["pnpm", "dlx", "@angular/cli@19", "new", "web"],
+                &["bunx", "@angular/cli@19", "new", "web"],
+            ],
+        );
+    }
+}
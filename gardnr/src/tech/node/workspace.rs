@@ -0,0 +1,215 @@
+use super::package_json::{PackageJson, package_name};
+use super::pm::{PackageManager, run_in};
+use std::{fs, io, path::Path};
+
+// Version ranges of the root tooling a workspace is set up with.
+const TURBO: &str = "^2.5.0";
+const NX: &str = "^21.1.0";
+const CONCURRENTLY: &str = "^9.1.0";
+
+/// Lock files the package managers write; members lose theirs to the root's.
+const LOCK_FILES: &[&str] = &[
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "bun.lock",
+    "bun.lockb",
+];
+
+/// Runs the root scripts of a workspace across its packages, with caching
+/// and dependency ordering.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TaskRunner {
+    Turbo,
+    Nx,
+}
+
+impl TaskRunner {
+    pub fn parse(name: &str) -> Option<TaskRunner> {
+        match name.to_lowercase().as_str() {
+            "turbo" | "turborepo" => Some(TaskRunner::Turbo),
+            "nx" => Some(TaskRunner::Nx),
+            _ => None,
+        }
+    }
+}
+
+/// Turn the Node.js components `members` of the project into the packages of
+/// one workspace: a root `package.json` listing them (`pnpm-workspace.yaml`
+/// for pnpm), root `dev`, `build` and `test` scripts that run the members'
+/// scripts, and a single install with one lock file at the root.
+pub fn create_workspace(
+    project_dir: &Path,
+    project_name: &str,
+    members: &[String],
+    pm: PackageManager,
+    task_runner: Option<TaskRunner>,
+) -> io::Result<()> {
+    println!(
+        "→ Creating {} workspace with {}",
+        pm.command(),
+        members.join(", ")
+    );
+
+    let mut root = PackageJson::new(project_dir);
+    root.set("name", package_name(project_name))
+        .set("private", true);
+    if pm == PackageManager::Pnpm {
+        let mut yaml = "packages:\n".to_string();
+        for member in members {
+            yaml.push_str(&format!("  - {}\n", member));
+        }
+        fs::write(project_dir.join("pnpm-workspace.yaml"), yaml)?;
+    } else {
+        root.set("workspaces", members.to_vec());
+    }
+    if let Some(version) = pm.version() {
+        root.set("packageManager", version);
+    }
+
+    match task_runner {
+        Some(TaskRunner::Turbo) => {
+            for script in ["dev", "build", "test"] {
+                root.set_script(script, &format!("turbo run {}", script));
+            }
+            root.set_dev_dependency("turbo", TURBO);
+            fs::write(project_dir.join("turbo.json"), TURBO_JSON)?;
+        }
+        Some(TaskRunner::Nx) => {
+            for script in ["dev", "build", "test"] {
+                root.set_script(script, &format!("nx run-many -t {}", script));
+            }
+            root.set_dev_dependency("nx", NX);
+            fs::write(project_dir.join("nx.json"), NX_JSON)?;
+        }
+        None => fan_out_scripts(project_dir, members, pm, &mut root)?,
+    }
+    root.save()?;
+
+    // The members were installed on their own; start over from the root
+    for member in members {
+        let member_dir = project_dir.join(member);
+        for lock_file in LOCK_FILES {
+            let path = member_dir.join(lock_file);
+            if path.is_file() {
+                fs::remove_file(path)?;
+            }
+        }
+        let node_modules = member_dir.join("node_modules");
+        if node_modules.is_dir() {
+            fs::remove_dir_all(node_modules)?;
+        }
+    }
+
+    println!("→ Installing workspace dependencies...");
+    let status = run_in(&pm.install(), project_dir)?;
+    if !status.success() {
+        eprintln!("⚠️  Failed to install workspace dependencies");
+    }
+
+    let project_name = project_dir.file_name().unwrap().to_str().unwrap();
+    println!(
+        "   - Run all: cd {} && {}",
+        project_name,
+        pm.run("dev").join(" ")
+    );
+    println!(
+        "   - Build all: cd {} && {}",
+        project_name,
+        pm.run("build").join(" ")
+    );
+
+    Ok(())
+}
+
+/// Root scripts calling each member that has the script: builds and tests
+/// one after another, dev servers side by side through concurrently.
+fn fan_out_scripts(
+    project_dir: &Path,
+    members: &[String],
+    pm: PackageManager,
+    root: &mut PackageJson,
+) -> io::Result<()> {
+    let mut packages = Vec::new();
+    for member in members {
+        if let Some(package) = PackageJson::open(&project_dir.join(member))? {
+            packages.push(package);
+        }
+    }
+    let with_script = |script: &str| -> Vec<String> {
+        packages
+            .iter()
+            .filter(|package| {
+                // npm init's placeholder test script only fails
+                package
+                    .script(script)
+                    .is_some_and(|command| !command.contains("no test specified"))
+            })
+            .filter_map(|package| package.name())
+            .map(|name| name.to_string())
+            .collect()
+    };
+
+    let dev = with_script("dev");
+    if !dev.is_empty() {
+        let commands: Vec<String> = dev
+            .iter()
+            .map(|name| format!("\"{}\"", pm.run_workspace(name, "dev").join(" ")))
+            .collect();
+        root.set_script(
+            "dev",
+            &format!("concurrently -n {} {}", dev.join(","), commands.join(" ")),
+        );
+        root.set_dev_dependency("concurrently", CONCURRENTLY);
+    }
+    for script in ["build", "test"] {
+        let names = with_script(script);
+        if !names.is_empty() {
+            let commands: Vec<String> = names
+                .iter()
+                .map(|name| pm.run_workspace(name, script).join(" "))
+                .collect();
+            root.set_script(script, &commands.join(" && "));
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds depend on the workspace dependencies' builds; dev servers are
+/// long-running and never cached.
+const TURBO_JSON: &str = r#"{
+  "$schema": "https://turbo.build/schema.json",
+  "tasks": {
+    "build": {
+      "dependsOn": ["^build"],
+      "outputs": ["dist/**", ".next/**", "!.next/cache/**", ".output/**"]
+    },
+    "test": {
+      "dependsOn": ["^build"]
+    },
+    "dev": {
+      "cache": false,
+      "persistent": true
+    }
+  }
+}
+"#;
+
+const NX_JSON: &str = r#"{
+  "$schema": "./node_modules/nx/schemas/nx-schema.json",
+  "targetDefaults": {
+    "build": {
+      "dependsOn": ["^build"],
+      "outputs": ["{projectRoot}/dist", "{projectRoot}/.next", "{projectRoot}/.output"],
+      "cache": true
+    },
+    "test": {
+      "cache": true
+    },
+    "dev": {
+      "cache": false
+    }
+  }
+}
+"#;
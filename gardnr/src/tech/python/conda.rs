@@ -0,0 +1,155 @@
+use super::env::Dependency;
+use serde_json::Value as Json;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The environment file written into conda-managed components.
+pub const ENVIRONMENT_FILE: &str = "environment.yml";
+
+/// The env exported with exact versions; `env create -f` recreates it.
+pub const LOCK_FILE: &str = "environment.lock.yml";
+
+/// Render `environment.yml` for the env `name` on Python `python`.
+///
+/// Packages are taken from conda-forge under their conda names; those with
+/// extras have no conda equivalent and go into the `pip:` section instead,
+/// as does the component itself when it is installed in `editable` mode.
+pub fn render_environment(
+    name: &str,
+    python: &str,
+    dependencies: &[&Dependency],
+    editable: bool,
+) -> String {
+    let mut content = format!(
+        "name: {}\nchannels:\n  - conda-forge\ndependencies:\n  - python={}\n  - pip\n",
+        name, python
+    );
+
+    let (pip, conda): (Vec<&Dependency>, Vec<&Dependency>) = dependencies
+        .iter()
+        .copied()
+        .partition(|dependency| !dependency.extras().is_empty());
+    for dependency in conda {
+        content.push_str(&format!("  - \"{}\"\n", match_spec(dependency)));
+    }
+    if !pip.is_empty() || editable {
+        content.push_str("  - pip:\n");
+        for dependency in pip {
+            content.push_str(&format!("      - \"{}\"\n", dependency.spec()));
+        }
+        if editable {
+            content.push_str("      - \"-e .\"\n");
+        }
+    }
+
+    content
+}
+
+/// Prefix of the conda env `name`, if it exists.
+pub fn env_prefix(command: &str, name: &str) -> Option<PathBuf> {
+    let output = Command::new(command)
+        .args(["env", "list", "--json"])
+        .output()
+        .ok()?;
+    let envs: Json = serde_json::from_slice(&output.stdout).ok()?;
+
+    envs.get("envs")?
+        .as_array()?
+        .iter()
+        .filter_map(|prefix| prefix.as_str())
+        .map(PathBuf::from)
+        .find(|prefix| prefix.file_name().is_some_and(|env| env == name))
+}
+
+/// Create the env `name` from the environment file in `dir`, or bring an
+/// existing one in line with it.
+pub fn sync_env(command: &str, dir: &Path, name: &str) -> io::Result<PathBuf> {
+    let action = if env_prefix(command, name).is_some() {
+        vec!["env", "update", "--prune"]
+    } else {
+        vec!["env", "create"]
+    };
+
+    println!(
+        "→ {} {} -n {} -f {}",
+        command,
+        action.join(" "),
+        name,
+        ENVIRONMENT_FILE
+    );
+    let status = Command::new(command)
+        .args(&action)
+        .args(["-n", name, "-f", ENVIRONMENT_FILE])
+        .current_dir(dir)
+        .status()?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "{} {} failed",
+            command,
+            action.join(" ")
+        )));
+    }
+    env_prefix(command, name)
+        .ok_or_else(|| io::Error::other(format!("conda env {} was not created", name)))
+}
+
+/// Export the exact versions in the env `name` to the lock file in `dir`.
+pub fn export_env(command: &str, dir: &Path, name: &str) -> io::Result<()> {
+    println!("→ {} env export -n {} > {}", command, name, LOCK_FILE);
+    let output = Command::new(command)
+        .args(["env", "export", "--no-builds", "-n", name])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!("{} env export failed", command)));
+    }
+    fs::write(dir.join(LOCK_FILE), output.stdout)
+}
+
+/// conda match spec for a dependency: a plain selector becomes conda's
+/// prefix match (`numpy=1.26`), anything else is used as-is.
+fn match_spec(dependency: &Dependency) -> String {
+    let name = conda_name(dependency.distribution());
+    match &dependency.selector {
+        Some(selector) if !selector.starts_with(['=', '<', '>', '!', '~']) => {
+            format!("{}={}", name, selector)
+        }
+        Some(selector) => format!("{}{}", name, selector),
+        None => format!("{}{}", name, dependency.fallback),
+    }
+}
+
+/// conda-forge name for a PyPI distribution: lower case (`Flask-CORS` is
+/// `flask-cors`), renamed where the two differ.
+fn conda_name(package: &str) -> String {
+    let package = package.to_lowercase();
+    match package.as_str() {
+        "torch" => "pytorch".to_string(),
+        "sklearn" => "scikit-learn".to_string(),
+        "cv2" | "opencv-python" => "opencv".to_string(),
+        _ => package,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowercases_package_names() {
+        let flask_cors = Dependency::new("Flask-CORS", None, ">=4.0");
+        assert_eq!(match_spec(&flask_cors), "flask-cors>=4.0");
+        let django = Dependency::new("Django", Some("5.1"), ">=4.2");
+        assert_eq!(match_spec(&django), "django=5.1");
+    }
+
+    #[test]
+    fn renames_packages_conda_knows_differently() {
+        let torch = Dependency::new("torch", Some(">=2.0"), ">=2.0");
+        assert_eq!(match_spec(&torch), "pytorch>=2.0");
+    }
+}
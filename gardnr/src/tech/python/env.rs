@@ -0,0 +1,1097 @@
+use super::pyproject::{self, Package};
+use super::{conda, pyenv};
+use crate::utils::is_installed;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Name of the virtual environment created inside every Python component.
+pub const VENV_DIR: &str = ".venv";
+
+/// Exact versions frozen from the environment, for tools without a lock file.
+pub const LOCK_FILE: &str = "requirements.lock";
+
+/// What creates a Python component's environment and manages its dependencies.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PyTool {
+    /// `python -m venv` (or pyenv-virtualenv) plus pip and `requirements.txt`
+    Pip,
+    /// uv with `pyproject.toml` and `uv.lock`
+    Uv,
+    /// Poetry with a `[tool.poetry]` project and `poetry.lock`
+    Poetry,
+    /// PDM with a PEP 621 project and `pdm.lock`
+    Pdm,
+    /// Hatch with a PEP 621 project on the hatchling backend; Hatch has no
+    /// lock file of its own, so the environment is frozen to `requirements.lock`
+    Hatch,
+    /// A named conda env described by `environment.yml`
+    Conda,
+    /// Same as `Conda`, using the faster mamba
+    Mamba,
+}
+
+impl PyTool {
+    pub fn parse(name: &str) -> Option<PyTool> {
+        match name.to_lowercase().as_str() {
+            "pip" | "venv" => Some(PyTool::Pip),
+            "uv" => Some(PyTool::Uv),
+            "poetry" => Some(PyTool::Poetry),
+            "pdm" => Some(PyTool::Pdm),
+            "hatch" => Some(PyTool::Hatch),
+            "conda" => Some(PyTool::Conda),
+            "mamba" => Some(PyTool::Mamba),
+            _ => None,
+        }
+    }
+
+    /// Name of the tool as `parse` accepts it.
+    pub fn name(self) -> &'static str {
+        self.command().unwrap_or("pip")
+    }
+
+    /// Executable of the tool, `None` for plain pip.
+    pub fn command(self) -> Option<&'static str> {
+        match self {
+            PyTool::Pip => None,
+            PyTool::Uv => Some("uv"),
+            PyTool::Poetry => Some("poetry"),
+            PyTool::Pdm => Some("pdm"),
+            PyTool::Hatch => Some("hatch"),
+            PyTool::Conda => Some("conda"),
+            PyTool::Mamba => Some("mamba"),
+        }
+    }
+
+    /// mamba or conda, whichever is installed, preferring mamba.
+    pub fn detect_conda() -> Option<PyTool> {
+        if is_installed(&["mamba"]).is_some() {
+            Some(PyTool::Mamba)
+        } else if is_installed(&["conda"]).is_some() {
+            Some(PyTool::Conda)
+        } else {
+            None
+        }
+    }
+
+    /// uv when it is installed, pip otherwise.
+    pub fn detect() -> PyTool {
+        if is_installed(&["uv"]).is_some() {
+            PyTool::Uv
+        } else {
+            PyTool::Pip
+        }
+    }
+}
+
+/// A package a scaffold depends on.
+pub struct Dependency {
+    /// Requirement name, possibly with extras (`uvicorn[standard]`)
+    pub package: String,
+    /// Version selector the user asked for, e.g. `5.1`
+    pub selector: Option<String>,
+    /// Range recorded when neither an installed version nor a selector is known
+    pub fallback: &'static str,
+}
+
+impl Dependency {
+    pub fn new(package: &str, selector: Option<&str>, fallback: &'static str) -> Dependency {
+        Dependency {
+            package: package.to_string(),
+            selector: selector.map(|selector| selector.to_string()),
+            fallback,
+        }
+    }
+
+    /// Distribution name without extras, as `importlib.metadata` knows it.
+    pub fn distribution(&self) -> &str {
+        self.package
+            .split_once('[')
+            .map_or(self.package.as_str(), |(name, _)| name)
+    }
+
+    /// Extras requested for the package, e.g. `standard` for `uvicorn[standard]`.
+    pub fn extras(&self) -> Vec<&str> {
+        self.package
+            .split_once('[')
+            .map(|(_, extras)| {
+                extras
+                    .trim_end_matches(']')
+                    .split(',')
+                    .map(|extra| extra.trim())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Version constraint without the package name: the selector if one was
+    /// given, else the fallback range, else `*`.
+    pub fn constraint(&self) -> String {
+        let constraint = match &self.selector {
+            Some(_) => self.requirement()[self.package.len()..].to_string(),
+            None => self.fallback.to_string(),
+        };
+        if constraint.is_empty() {
+            "*".to_string()
+        } else {
+            constraint
+        }
+    }
+
+    /// PEP 508 requirement with the selector or fallback range.
+    pub fn spec(&self) -> String {
+        match self.constraint().as_str() {
+            "*" => self.package.clone(),
+            constraint => format!("{}{}", self.package, constraint),
+        }
+    }
+
+    fn requirement(&self) -> String {
+        pip_requirement(&self.package, self.selector.as_deref())
+    }
+}
+
+/// The environment a component's packages are installed into.
+pub struct PythonEnv {
+    pub tool: PyTool,
+    /// Interpreter of the environment
+    pub python: String,
+    dir: PathBuf,
+    /// Project name for the tools that need one in `pyproject.toml`
+    name: String,
+    /// `major.minor` (or requested) Python version
+    python_version: String,
+    /// PEP 440 `requires-python` for the tools that need one
+    requires_python: String,
+    /// Package the component is built as, if it is one
+    pub package: Option<Package>,
+}
+
+impl PythonEnv {
+    /// Create (or reuse) the environment for the component in `dir`.
+    ///
+    /// With pip, a `requested` Python version gets a pyenv virtualenv named
+    /// `env_name` selected through `.python-version`; otherwise, or when
+    /// pyenv is missing, the component gets a plain `.venv`. uv creates the
+    /// `.venv` itself and fetches the requested Python if needed. Poetry, PDM
+    /// and Hatch get a generated `pyproject.toml` and an in-project `.venv`.
+    /// conda and mamba create a named env from `environment.yml`.
+    /// If no environment can be created at all, nothing is installed: packages
+    /// never go into the system interpreter's site-packages.
+    pub fn setup(
+        tool: PyTool,
+        dir: &Path,
+        env_name: &str,
+        base_python: &str,
+        requested: Option<&str>,
+    ) -> io::Result<PythonEnv> {
+        let tool = match tool.command() {
+            Some(command) if is_installed(&[command]).is_none() => {
+                eprintln!("⚠️  {} not found, falling back to venv + pip", command);
+                PyTool::Pip
+            }
+            _ => tool,
+        };
+        let python_version = requested
+            .map(|version| version.to_string())
+            .or_else(|| minor_version(base_python))
+            .unwrap_or_else(|| "3.9".to_string());
+        let requires_python = match requested {
+            Some(version) => format!("=={}.*", version),
+            None => format!(">={}", python_version),
+        };
+
+        let created = match tool {
+            PyTool::Pip => setup_venv(dir, env_name, base_python, requested)
+                .map(|python| (PyTool::Pip, python)),
+            PyTool::Uv => setup_uv(dir, env_name, requested),
+            PyTool::Conda | PyTool::Mamba => setup_conda(tool, dir, env_name, &python_version),
+            tool => setup_managed(
+                tool,
+                dir,
+                env_name,
+                base_python,
+                requested,
+                &requires_python,
+            ),
+        }
+        .or_else(|e| {
+            if tool == PyTool::Pip {
+                return Err(e);
+            }
+            eprintln!(
+                "⚠️  {} setup failed, falling back to venv + pip: {}",
+                tool.command().unwrap_or_default(),
+                e
+            );
+            setup_venv(dir, env_name, base_python, requested).map(|python| (PyTool::Pip, python))
+        });
+        let (tool, python) = created.map_err(|e| {
+            io::Error::other(format!(
+                "could not create a virtual environment: {} - install Python's venv \
+                 module (e.g. the python3-venv package) and try again",
+                e
+            ))
+        })?;
+        // Absolute, so the interpreter can be run from inside the component
+        let python = std::path::absolute(&python)
+            .unwrap_or(python)
+            .to_string_lossy()
+            .to_string();
+
+        write_gitignore(dir)?;
+
+        Ok(PythonEnv {
+            tool,
+            python,
+            dir: dir.to_path_buf(),
+            name: env_name.to_string(),
+            python_version,
+            requires_python,
+            package: None,
+        })
+    }
+
+    /// The existing environment of a component set up with `tool`, e.g. to
+    /// refresh its lock.
+    pub fn open(tool: PyTool, dir: &Path, env_name: &str) -> PythonEnv {
+        let venv_python = venv_bin(&dir.join(VENV_DIR), "python");
+        let python = match tool {
+            PyTool::Conda | PyTool::Mamba => {
+                conda::env_prefix(tool.command().unwrap_or_default(), env_name)
+                    .map(|prefix| prefix.join("bin").join("python"))
+            }
+            _ => venv_python.exists().then_some(venv_python),
+        }
+        .map(|python| std::path::absolute(&python).unwrap_or(python))
+        .map(|python| python.to_string_lossy().to_string())
+        // pyenv virtualenvs are picked up through `.python-version`
+        .unwrap_or_else(|| "python".to_string());
+
+        PythonEnv {
+            tool,
+            python,
+            dir: dir.to_path_buf(),
+            name: env_name.to_string(),
+            python_version: String::new(),
+            requires_python: String::new(),
+            package: None,
+        }
+    }
+
+    /// Pin every package in the environment to its exact version:
+    /// `requirements.lock` frozen from the environment for pip and Hatch,
+    /// the tool's own lock file for uv, Poetry and PDM, and an exported
+    /// `environment.lock.yml` for conda and mamba.
+    pub fn lock(&self) -> io::Result<()> {
+        let command = self.tool.command().unwrap_or_default();
+        match self.tool {
+            PyTool::Pip | PyTool::Hatch => self.freeze(),
+            PyTool::Conda | PyTool::Mamba => conda::export_env(command, &self.dir, &self.name),
+            PyTool::Uv | PyTool::Poetry | PyTool::Pdm => {
+                println!("→ {} lock", command);
+                let status = Command::new(command)
+                    .arg("lock")
+                    .current_dir(&self.dir)
+                    .status()?;
+
+                if !status.success() {
+                    return Err(io::Error::other(format!("{} lock failed", command)));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Install `dependencies` and record them in the component's dependency
+    /// file(s). Returns the installed version of every dependency that is
+    /// installed, keyed by package, whether or not it matches its selector.
+    ///
+    /// Poetry, PDM and Hatch always work from the generated `pyproject.toml`;
+    /// packages get one with every tool.
+    pub fn add(&self, dependencies: &[Dependency]) -> io::Result<BTreeMap<String, String>> {
+        let managed = matches!(self.tool, PyTool::Poetry | PyTool::Pdm | PyTool::Hatch);
+        if managed || self.package.is_some() {
+            fs::write(
+                self.dir.join("pyproject.toml"),
+                pyproject::render(
+                    self.tool,
+                    &self.name,
+                    &self.requires_python,
+                    dependencies,
+                    self.package.as_ref(),
+                ),
+            )?;
+        }
+
+        match self.tool {
+            PyTool::Pip => self.add_with_pip(dependencies),
+            PyTool::Uv => self.add_with_uv(dependencies),
+            PyTool::Poetry | PyTool::Pdm | PyTool::Hatch => self.add_with_manager(dependencies),
+            PyTool::Conda | PyTool::Mamba => self.add_with_conda(dependencies),
+        }
+    }
+
+    /// The files dependencies are recorded in, for scaffold summaries.
+    pub fn dependency_files(&self) -> String {
+        let files = match self.tool {
+            PyTool::Pip => "requirements.txt",
+            PyTool::Uv => "pyproject.toml, uv.lock",
+            PyTool::Poetry => "pyproject.toml, poetry.lock",
+            PyTool::Pdm => "pyproject.toml, pdm.lock",
+            PyTool::Hatch => "pyproject.toml, requirements.lock",
+            PyTool::Conda | PyTool::Mamba => conda::ENVIRONMENT_FILE,
+        };
+        if self.package.is_some() && !files.starts_with("pyproject.toml") {
+            format!("pyproject.toml, {}", files)
+        } else {
+            files.to_string()
+        }
+    }
+
+    /// Name of the environment: the conda env or pyenv virtualenv, and the
+    /// `pyproject.toml` project name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Name of the conda env, for conda-managed components.
+    pub fn conda_env(&self) -> Option<&str> {
+        matches!(self.tool, PyTool::Conda | PyTool::Mamba).then_some(self.name.as_str())
+    }
+
+    /// How to invoke `executable` from inside the component in run hints.
+    pub fn run_cmd(&self, executable: &str) -> String {
+        match self.tool {
+            PyTool::Conda | PyTool::Mamba => format!(
+                "{} run -n {} {}",
+                self.tool.command().unwrap_or_default(),
+                self.name,
+                executable
+            ),
+            PyTool::Uv | PyTool::Poetry | PyTool::Pdm | PyTool::Hatch => format!(
+                "{} run {}",
+                self.tool.command().unwrap_or_default(),
+                executable
+            ),
+            PyTool::Pip if self.dir.join(VENV_DIR).is_dir() => {
+                venv_bin(Path::new(VENV_DIR), executable)
+                    .display()
+                    .to_string()
+            }
+            // pyenv virtualenvs are picked up through `.python-version`
+            PyTool::Pip => executable.to_string(),
+        }
+    }
+
+    fn add_with_pip(&self, dependencies: &[Dependency]) -> io::Result<BTreeMap<String, String>> {
+        // Packages also need their dev tools, which stay out of requirements.txt
+        let dev_dependencies = match self.package {
+            Some(_) => pyproject::dev_dependencies(),
+            None => Vec::new(),
+        };
+        let wanted: Vec<&Dependency> = dependencies.iter().chain(&dev_dependencies).collect();
+        let mut installed = installed_versions(
+            &self.python,
+            wanted.iter().map(|dependency| dependency.distribution()),
+        );
+
+        let mut missing = Vec::new();
+        for dependency in wanted {
+            match installed.get(dependency.distribution()).cloned() {
+                Some(version) if dependency_matches(dependency, &version) => {
+                    println!("→ {} {} is already installed", dependency.package, version);
+                }
+                Some(version) => {
+                    println!(
+                        "Version {} of {} is installed, but {} was requested.",
+                        version,
+                        dependency.package,
+                        dependency.requirement()
+                    );
+                    missing.push(dependency.requirement());
+                }
+                None => missing.push(dependency.requirement()),
+            }
+        }
+
+        if !missing.is_empty() {
+            if prompt_install(&missing) {
+                println!("→ Installing {} with pip...", missing.join(" "));
+                let status = Command::new(&self.python)
+                    .args(["-m", "pip", "install"])
+                    .args(&missing)
+                    .status()?;
+
+                if !status.success() {
+                    eprintln!("⚠️  Failed to install {}", missing.join(" "));
+                }
+                installed = installed_versions(
+                    &self.python,
+                    dependencies.iter().map(Dependency::distribution),
+                );
+            } else {
+                println!("→ Skipping installation of {}", missing.join(" "));
+            }
+        }
+
+        if let Some(package) = &self.package {
+            println!("→ Installing {} in editable mode...", package.script);
+            let status = Command::new(&self.python)
+                .args(["-m", "pip", "install", "--no-deps", "-e"])
+                .arg(&self.dir)
+                .status()?;
+
+            if !status.success() {
+                eprintln!(
+                    "⚠️  Could not install {} - run: pip install -e .",
+                    package.script
+                );
+            }
+        }
+
+        let versions = matching_versions(dependencies, &installed);
+        // A lock of a half-installed environment would pin the wrong stack
+        if versions.len() == dependencies.len() {
+            if let Err(e) = self.lock() {
+                eprintln!("⚠️  {}", e);
+            }
+        } else {
+            // Components live directly in the project directory
+            let project_dir = self.dir.parent().unwrap_or(&self.dir);
+            let project_name = project_dir.file_name().unwrap_or_default();
+            let path = project_dir
+                .parent()
+                .filter(|path| !path.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            println!(
+                "→ Not writing {} until everything is installed - then run: gardnr -m lock -n {} -p {}",
+                LOCK_FILE,
+                project_name.to_string_lossy(),
+                path.display()
+            );
+        }
+
+        let mut requirements = String::new();
+        if dependencies.is_empty() {
+            requirements.push_str("# Add your Python dependencies here\n");
+        } else if versions.len() < dependencies.len() {
+            requirements
+                .push_str("# Not everything is installed - run: pip install -r requirements.txt\n");
+        }
+        for dependency in dependencies {
+            requirements.push_str(&requirement_line(
+                dependency,
+                versions
+                    .get(&dependency.package)
+                    .map(|version| version.as_str()),
+            ));
+            requirements.push('\n');
+        }
+        fs::write(self.dir.join("requirements.txt"), requirements)?;
+
+        Ok(installed_packages(dependencies, &installed))
+    }
+
+    fn add_with_uv(&self, dependencies: &[Dependency]) -> io::Result<BTreeMap<String, String>> {
+        if !dependencies.is_empty() {
+            let requirements: Vec<String> =
+                dependencies.iter().map(Dependency::requirement).collect();
+            println!("→ uv add {}", requirements.join(" "));
+            let status = Command::new("uv")
+                .arg("add")
+                .args(&requirements)
+                .current_dir(&self.dir)
+                .status()?;
+
+            if !status.success() {
+                eprintln!("⚠️  uv add failed - run: uv add {}", requirements.join(" "));
+            }
+        }
+
+        // `uv add` locks as it goes; this covers projects without dependencies
+        if !self.dir.join("uv.lock").exists() {
+            println!("→ uv lock");
+            let status = Command::new("uv")
+                .arg("lock")
+                .current_dir(&self.dir)
+                .status()?;
+            if !status.success() {
+                eprintln!("⚠️  uv lock failed");
+            }
+        }
+
+        Ok(self.installed_versions(dependencies))
+    }
+
+    /// Let the tool lock and install what the generated `pyproject.toml` asks for.
+    fn add_with_manager(
+        &self,
+        dependencies: &[Dependency],
+    ) -> io::Result<BTreeMap<String, String>> {
+        let steps: &[&[&str]] = match self.tool {
+            PyTool::Poetry => &[&["poetry", "lock"], &["poetry", "install"]],
+            PyTool::Pdm => &[&["pdm", "install"]],
+            // `hatch run` brings the environment in line with pyproject.toml
+            _ => &[&["hatch", "run", "python", "-c", "pass"]],
+        };
+        for step in steps {
+            println!("→ {}", step.join(" "));
+            let status = Command::new(step[0])
+                .args(&step[1..])
+                .current_dir(&self.dir)
+                .status()?;
+
+            if !status.success() {
+                eprintln!("⚠️  {} failed", step.join(" "));
+                break;
+            }
+        }
+
+        if self.tool == PyTool::Hatch
+            && let Err(e) = self.lock()
+        {
+            eprintln!("⚠️  {}", e);
+        }
+
+        Ok(self.installed_versions(dependencies))
+    }
+
+    fn add_with_conda(&self, dependencies: &[Dependency]) -> io::Result<BTreeMap<String, String>> {
+        let mut packages: Vec<&Dependency> = dependencies.iter().collect();
+        let dev_dependencies = pyproject::dev_dependencies();
+        if self.package.is_some() {
+            packages.extend(&dev_dependencies);
+        }
+        fs::write(
+            self.dir.join(conda::ENVIRONMENT_FILE),
+            conda::render_environment(
+                &self.name,
+                &self.python_version,
+                &packages,
+                self.package.is_some(),
+            ),
+        )?;
+
+        let synced = conda::sync_env(
+            self.tool.command().unwrap_or_default(),
+            &self.dir,
+            &self.name,
+        );
+        if let Err(e) = synced.and_then(|_| self.lock()) {
+            eprintln!("⚠️  {}", e);
+        }
+
+        Ok(self.installed_versions(dependencies))
+    }
+
+    /// Write `requirements.lock` from `pip freeze`, leaving out the
+    /// component itself when it is installed in editable mode.
+    fn freeze(&self) -> io::Result<()> {
+        println!("→ Freezing the environment into {}", LOCK_FILE);
+        let output = Command::new(&self.python)
+            .args(["-m", "pip", "freeze", "--exclude-editable"])
+            .current_dir(&self.dir)
+            .output()?;
+
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "Could not freeze the environment into {}",
+                LOCK_FILE
+            )));
+        }
+        fs::write(self.dir.join(LOCK_FILE), output.stdout)
+    }
+
+    /// After the tool resolved the selectors, whatever it installed counts.
+    fn installed_versions(&self, dependencies: &[Dependency]) -> BTreeMap<String, String> {
+        let installed = installed_versions(
+            &self.python,
+            dependencies.iter().map(Dependency::distribution),
+        );
+        installed_packages(dependencies, &installed)
+    }
+}
+
+/// Set up the component with uv: a `pyproject.toml` from `uv init` and a
+/// `.venv` from `uv venv`, pinned to the `requested` Python.
+fn setup_uv(dir: &Path, name: &str, requested: Option<&str>) -> io::Result<(PyTool, PathBuf)> {
+    let python_args: Vec<&str> = requested
+        .map(|version| vec!["--python", version])
+        .unwrap_or_default();
+
+    if !dir.join("pyproject.toml").exists() {
+        println!("→ uv init --bare --name {}", name);
+        let status = Command::new("uv")
+            .args(["init", "--bare", "--no-workspace", "--name", name])
+            .args(&python_args)
+            .current_dir(dir)
+            .status()?;
+
+        if !status.success() {
+            return Err(io::Error::other("uv init failed"));
+        }
+    }
+
+    if let Some(version) = requested {
+        let status = Command::new("uv")
+            .args(["python", "pin", version])
+            .current_dir(dir)
+            .status()?;
+        if !status.success() {
+            return Err(io::Error::other(format!(
+                "uv python pin {} failed",
+                version
+            )));
+        }
+    }
+
+    println!("→ uv venv {}", VENV_DIR);
+    let status = Command::new("uv")
+        .args(["venv", "--allow-existing", VENV_DIR])
+        .args(&python_args)
+        .current_dir(dir)
+        .status()?;
+
+    let venv_python = venv_bin(&dir.join(VENV_DIR), "python");
+    if !status.success() || !venv_python.exists() {
+        return Err(io::Error::other("uv venv failed"));
+    }
+
+    Ok((PyTool::Uv, venv_python))
+}
+
+/// Set up the component with a named conda env holding just Python; the
+/// packages follow once they are known.
+fn setup_conda(
+    tool: PyTool,
+    dir: &Path,
+    name: &str,
+    python_version: &str,
+) -> io::Result<(PyTool, PathBuf)> {
+    fs::write(
+        dir.join(conda::ENVIRONMENT_FILE),
+        conda::render_environment(name, python_version, &[], false),
+    )?;
+    let prefix =
+        conda::sync_env(tool.command().unwrap_or_default(), dir, name).inspect_err(|_| {
+            // The venv fallback should not leave an environment file behind
+            let _ = fs::remove_file(dir.join(conda::ENVIRONMENT_FILE));
+        })?;
+
+    Ok((tool, prefix.join("bin").join("python")))
+}
+
+/// Set up the component for Poetry, PDM or Hatch: a generated
+/// `pyproject.toml` without dependencies yet, and an in-project `.venv`
+/// created by the tool from the requested (or base) Python.
+fn setup_managed(
+    tool: PyTool,
+    dir: &Path,
+    name: &str,
+    base_python: &str,
+    requested: Option<&str>,
+    requires_python: &str,
+) -> io::Result<(PyTool, PathBuf)> {
+    let python = requested
+        .and_then(|version| {
+            is_installed(&[&format!("python{}", version)])
+                .map(PathBuf::from)
+                .or_else(|| pyenv::interpreter(version))
+        })
+        .unwrap_or_else(|| PathBuf::from(base_python));
+    let python = python.to_string_lossy().to_string();
+
+    fs::write(
+        dir.join("pyproject.toml"),
+        pyproject::render(tool, name, requires_python, &[], None),
+    )?;
+
+    let venv_python = venv_bin(&dir.join(VENV_DIR), "python");
+    let venv_python_arg = venv_python.to_string_lossy().to_string();
+    let steps: Vec<Vec<&str>> = match tool {
+        PyTool::Poetry => {
+            // Keep the environment inside the component like everywhere else
+            fs::write(
+                dir.join("poetry.toml"),
+                "[virtualenvs]\nin-project = true\n",
+            )?;
+            vec![vec!["poetry", "env", "use", &python]]
+        }
+        PyTool::Pdm => vec![
+            vec!["pdm", "venv", "create", "--force", &python],
+            vec!["pdm", "use", "-f", &venv_python_arg],
+        ],
+        _ => vec![vec!["hatch", "env", "create"]],
+    };
+    for step in steps {
+        println!("→ {}", step.join(" "));
+        // Hatch picks the interpreter for new environments from HATCH_PYTHON
+        let status = Command::new(step[0])
+            .args(&step[1..])
+            .env("HATCH_PYTHON", &python)
+            .current_dir(dir)
+            .status()?;
+
+        if !status.success() {
+            return Err(io::Error::other(format!("{} failed", step.join(" "))));
+        }
+    }
+
+    if !venv_python.exists() {
+        return Err(io::Error::other(format!(
+            "{} did not create {}",
+            tool.command().unwrap_or_default(),
+            VENV_DIR
+        )));
+    }
+
+    Ok((tool, venv_python))
+}
+
+/// Create the environment for a pip-managed component and return its
+/// interpreter: a pyenv virtualenv when a Python version is `requested` and
+/// pyenv is available, a plain `.venv` otherwise.
+fn setup_venv(
+    dir: &Path,
+    env_name: &str,
+    base_python: &str,
+    requested: Option<&str>,
+) -> io::Result<PathBuf> {
+    let Some(requested) = requested else {
+        return create_venv(dir, base_python);
+    };
+
+    if pyenv::is_available() {
+        match pyenv::setup_virtualenv(dir, env_name, requested) {
+            Ok(env_python) => return Ok(env_python),
+            Err(e) => eprintln!("⚠️  pyenv setup failed, using a plain venv: {}", e),
+        }
+    }
+
+    let requested_python = is_installed(&[&format!("python{}", requested)])
+        .map(PathBuf::from)
+        .or_else(|| pyenv::interpreter(requested));
+    match requested_python {
+        Some(python) => create_venv(dir, &python.to_string_lossy()),
+        None => {
+            eprintln!(
+                "⚠️  Python {} not found, creating the venv with {}",
+                requested, base_python
+            );
+            create_venv(dir, base_python)
+        }
+    }
+}
+
+/// Create (or reuse) the virtual environment in `dir` and return its interpreter.
+fn create_venv(dir: &Path, python_cmd: &str) -> io::Result<PathBuf> {
+    let venv_dir = dir.join(VENV_DIR);
+    let venv_python = venv_bin(&venv_dir, "python");
+
+    if venv_python.exists() {
+        println!(
+            "→ Using existing virtual environment {}",
+            venv_dir.display()
+        );
+    } else {
+        println!("→ Creating virtual environment {}", venv_dir.display());
+        let status = Command::new(python_cmd)
+            .args(["-m", "venv", VENV_DIR])
+            .current_dir(dir)
+            .status()?;
+
+        if !status.success() || !venv_python.exists() {
+            return Err(io::Error::other(format!("{} -m venv failed", python_cmd)));
+        }
+    }
+
+    Ok(venv_python)
+}
+
+/// Path of `executable` inside the virtual environment at `venv_dir`.
+fn venv_bin(venv_dir: &Path, executable: &str) -> PathBuf {
+    if cfg!(windows) {
+        venv_dir.join("Scripts").join(format!("{}.exe", executable))
+    } else {
+        venv_dir.join("bin").join(executable)
+    }
+}
+
+fn write_gitignore(dir: &Path) -> io::Result<()> {
+    let gitignore = dir.join(".gitignore");
+    if gitignore.exists() {
+        return Ok(());
+    }
+
+    fs::write(
+        gitignore,
+        format!("{}/\n__pycache__/\n*.py[cod]\n.env\n", VENV_DIR),
+    )
+}
+
+/// `major.minor` of the interpreter `python_cmd`.
+fn minor_version(python_cmd: &str) -> Option<String> {
+    let output = Command::new(python_cmd)
+        .args(["-c", "import sys; print('%d.%d' % sys.version_info[:2])"])
+        .output()
+        .ok()?;
+
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !version.is_empty()).then_some(version)
+}
+
+/// Script printing the installed version of every distribution named in
+/// its arguments as one JSON object, leaving out those that are missing.
+const VERSIONS_SCRIPT: &str = "\
+import importlib.metadata, json, sys
+versions = {}
+for name in sys.argv[1:]:
+    try:
+        versions[name] = importlib.metadata.version(name)
+    except importlib.metadata.PackageNotFoundError:
+        pass
+print(json.dumps(versions))";
+
+/// Versions of the `packages` installed for `python_cmd`, keyed by the
+/// distribution names as given. One interpreter start covers them all.
+fn installed_versions<'a>(
+    python_cmd: &str,
+    packages: impl IntoIterator<Item = &'a str>,
+) -> BTreeMap<String, String> {
+    let packages: Vec<&str> = packages.into_iter().collect();
+    if packages.is_empty() {
+        return BTreeMap::new();
+    }
+
+    let output = Command::new(python_cmd)
+        .args(["-c", VERSIONS_SCRIPT])
+        .args(&packages)
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            serde_json::from_slice(&output.stdout).unwrap_or_default()
+        }
+        _ => BTreeMap::new(),
+    }
+}
+
+/// The `installed` versions of `dependencies`, keyed by package.
+fn installed_packages(
+    dependencies: &[Dependency],
+    installed: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    dependencies
+        .iter()
+        .filter_map(|dependency| {
+            let version = installed.get(dependency.distribution())?;
+            Some((dependency.package.clone(), version.clone()))
+        })
+        .collect()
+}
+
+/// The `installed` versions of `dependencies` that match their selectors,
+/// keyed by package.
+fn matching_versions(
+    dependencies: &[Dependency],
+    installed: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    dependencies
+        .iter()
+        .filter_map(|dependency| {
+            let version = installed.get(dependency.distribution())?;
+            dependency_matches(dependency, version)
+                .then(|| (dependency.package.clone(), version.clone()))
+        })
+        .collect()
+}
+
+/// Turn a version selector into a pip requirement: `5.1` means any 5.1.x
+/// release, a full `5.1.4` that exact release, and anything starting with
+/// a comparison operator (`>=4.2,<5`) is passed through as-is.
+fn pip_requirement(package: &str, selector: Option<&str>) -> String {
+    match selector {
+        None => package.to_string(),
+        Some(selector) if selector.starts_with(['=', '<', '>', '!', '~']) => {
+            format!("{}{}", package, selector)
+        }
+        Some(selector) if selector.split('.').count() >= 3 => {
+            format!("{}=={}", package, selector)
+        }
+        Some(selector) => format!("{}=={}.*", package, selector),
+    }
+}
+
+/// Whether `installed` satisfies the dependency's selector, read the way
+/// `pip_requirement` turns it into a requirement.
+fn dependency_matches(dependency: &Dependency, installed: &str) -> bool {
+    let Some(selector) = &dependency.selector else {
+        return true;
+    };
+    if !selector.starts_with(['=', '<', '>', '!', '~']) {
+        let installed: Vec<&str> = installed.split('.').collect();
+        let wanted: Vec<&str> = selector.split('.').collect();
+        return wanted.len() <= installed.len()
+            && wanted.iter().zip(&installed).all(|(a, b)| a == b);
+    }
+    selector
+        .split(',')
+        .all(|clause| clause_matches(clause.trim(), installed))
+}
+
+/// Whether `installed` satisfies one PEP 440 clause such as `>=4.2` or
+/// `==5.1.*`. Release numbers are compared; clauses that can't be read are
+/// left to the installer and count as satisfied.
+fn clause_matches(clause: &str, installed: &str) -> bool {
+    let Some((operator, wanted)) = ["===", "~=", "==", "!=", ">=", "<=", ">", "<"]
+        .iter()
+        .find_map(|operator| Some((*operator, clause.strip_prefix(operator)?.trim())))
+    else {
+        return true;
+    };
+    let installed_release = release(installed);
+
+    if let Some(prefix) = wanted.strip_suffix(".*") {
+        let prefix = release(prefix);
+        let matches = installed_release.len() >= prefix.len()
+            && installed_release[..prefix.len()] == prefix[..];
+        return match operator {
+            "==" => matches,
+            "!=" => !matches,
+            _ => true,
+        };
+    }
+
+    let wanted_release = release(wanted);
+    let ordering = compare_releases(&installed_release, &wanted_release);
+    match operator {
+        "===" => installed == wanted,
+        "==" => ordering.is_eq(),
+        "!=" => ordering.is_ne(),
+        ">=" => ordering.is_ge(),
+        "<=" => ordering.is_le(),
+        ">" => ordering.is_gt(),
+        "<" => ordering.is_lt(),
+        // `~=2.2` means `>=2.2, ==2.*`
+        _ => {
+            let prefix = &wanted_release[..wanted_release.len().saturating_sub(1).max(1)];
+            ordering.is_ge()
+                && installed_release.len() >= prefix.len()
+                && installed_release[..prefix.len()] == prefix[..]
+        }
+    }
+}
+
+/// Numeric release segments of a version, `5.1rc1` giving `[5, 1]`.
+fn release(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map_while(|part| {
+            let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse().ok()
+        })
+        .collect()
+}
+
+/// Compare releases with missing segments read as zero, so `5.0 == 5`.
+fn compare_releases(a: &[u64], b: &[u64]) -> std::cmp::Ordering {
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| {
+            a.get(i)
+                .copied()
+                .unwrap_or(0)
+                .cmp(&b.get(i).copied().unwrap_or(0))
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(std::cmp::Ordering::Equal)
+}
+
+/// Line for a dependency in `requirements.txt`: pinned to the installed
+/// version when known, otherwise the requested selector, otherwise the
+/// fallback range.
+fn requirement_line(dependency: &Dependency, installed: Option<&str>) -> String {
+    match (installed, &dependency.selector) {
+        (Some(installed), _) => format!("{}=={}", dependency.package, installed),
+        (None, Some(_)) => dependency.requirement(),
+        (None, None) => format!("{}{}", dependency.package, dependency.fallback),
+    }
+}
+
+fn prompt_install(requirements: &[String]) -> bool {
+    println!("Not installed: {}", requirements.join(", "));
+    print!("Install now? (Y/n): ");
+    let _ = io::stdout().flush();
+
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(_) => {
+            let input = input.trim().to_lowercase();
+            input.is_empty() || input == "y" || input == "yes"
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(selector: &str, installed: &str) -> bool {
+        dependency_matches(&Dependency::new("Django", Some(selector), ""), installed)
+    }
+
+    #[test]
+    fn any_version_matches_without_selector() {
+        let dependency = Dependency::new("Django", None, ">=4.2");
+        assert!(dependency_matches(&dependency, "5.1.4"));
+    }
+
+    #[test]
+    fn exact_selector_matches_only_that_release() {
+        assert!(matches("5.1.4", "5.1.4"));
+        assert!(!matches("5.1.4", "5.1.3"));
+        assert!(!matches("5.1.4", "5.1.40"));
+    }
+
+    #[test]
+    fn partial_selector_matches_the_release_series() {
+        assert!(matches("5.1", "5.1.4"));
+        assert!(matches("5", "5.0"));
+        assert!(!matches("5.1", "5.10.1"));
+        assert!(!matches("5.1", "5.2.0"));
+    }
+
+    #[test]
+    fn range_selector_is_evaluated() {
+        assert!(matches(">=4.2,<5", "4.2.16"));
+        assert!(matches(">=4.2, <5", "4.9"));
+        assert!(!matches(">=4.2,<5", "5.0"));
+        assert!(!matches(">=4.2,<5", "4.1.9"));
+        assert!(matches("!=5.0.1", "5.0.2"));
+        assert!(!matches("!=5.0.1", "5.0.1"));
+        assert!(matches("==5.1.*", "5.1.3"));
+        assert!(!matches("==5.1.*", "5.2"));
+        assert!(matches("~=2.2", "2.9"));
+        assert!(!matches("~=2.2", "3.0"));
+        assert!(matches("~=1.4.5", "1.4.9"));
+        assert!(!matches("~=1.4.5", "1.5.0"));
+        assert!(matches("==5.0", "5"));
+    }
+
+    #[test]
+    fn pip_requirement_reads_selectors() {
+        assert_eq!(pip_requirement("Django", None), "Django");
+        assert_eq!(pip_requirement("Django", Some("5.1.4")), "Django==5.1.4");
+        assert_eq!(pip_requirement("Django", Some("5.1")), "Django==5.1.*");
+        assert_eq!(
+            pip_requirement("Django", Some(">=4.2,<5")),
+            "Django>=4.2,<5"
+        );
+        assert_eq!(pip_requirement("Django", Some("~=5.1")), "Django~=5.1");
+    }
+}
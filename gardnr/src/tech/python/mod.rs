@@ -0,0 +1,1121 @@
+pub mod conda;
+pub mod env;
+pub mod pyenv;
+pub mod pyproject;
+
+use super::{TechSpec, cors_var, port_var, title_var};
+use crate::template::vars::{self, TemplateVar, VarKind};
+use crate::template::{self, TemplateContext};
+use crate::utils::{DEFAULT_EXECUTABLES, is_installed};
+use env::{Dependency, PyTool, PythonEnv};
+use pyproject::Package;
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+};
+
+/// Variables the scaffold for `tech` can be customised with.
+pub fn variables(tech: &str) -> Vec<TemplateVar> {
+    match tech.to_lowercase().as_str() {
+        "django" => vec![
+            TemplateVar::new("app", VarKind::String, "core")
+                .help("Name of the first Django app")
+                .validator(
+                    "{% if app == project %}the app needs a name other than the project{% endif %}",
+                ),
+            TemplateVar::new("drf", VarKind::Bool, "false").help("Add Django REST Framework?"),
+        ],
+        "flask" => vec![
+            title_var(),
+            port_var(5000),
+            cors_var(),
+            TemplateVar::new("factory", VarKind::Bool, "false")
+                .help("Use an app factory with blueprints, config classes and tests?"),
+        ],
+        "fastapi" => vec![
+            title_var(),
+            port_var(8000),
+            cors_var(),
+            TemplateVar::new("routers", VarKind::Bool, "false")
+                .help("Use a package layout with routers, settings and tests?"),
+            TemplateVar::new("database", VarKind::Bool, "false")
+                .help("Add SQLAlchemy and Alembic?"),
+        ],
+        "pyramid" => vec![port_var(6543)],
+        "python-cli" => vec![
+            title_var(),
+            TemplateVar::new(
+                "framework",
+                VarKind::Choice(vec!["typer".to_string(), "click".to_string()]),
+                "typer",
+            )
+            .help("CLI framework"),
+        ],
+        "python" => vec![title_var()],
+        tech if let Some(app) = python_app(tech) => {
+            let mut variables = vec![title_var(), port_var(app.port)];
+            if app.cors {
+                variables.push(cors_var());
+            }
+            variables
+        }
+        tech if is_data_science_package(tech) => vec![title_var()],
+        _ => Vec::new(),
+    }
+}
+
+/// A framework whose scaffold is a single `app.py` from its built-in template.
+struct PythonApp {
+    tech: &'static str,
+    name: &'static str,
+    port: u16,
+    /// Whether the app sets up CORS from `cors_origins`
+    cors: bool,
+    /// Requirements with their fallback ranges; the framework comes first
+    dependencies: &'static [(&'static str, &'static str)],
+    /// Further templates, by template file and destination in the component
+    files: &'static [(&'static str, &'static str)],
+    /// Run hints as executable and arguments, `{port}` standing for the port
+    run: &'static [(&'static str, &'static str)],
+}
+
+const PYTHON_APPS: &[PythonApp] = &[
+    PythonApp {
+        tech: "litestar",
+        name: "Litestar",
+        port: 8000,
+        cors: true,
+        dependencies: &[("litestar", ">=2.0"), ("uvicorn[standard]", ">=0.24,<1.0")],
+        files: &[],
+        run: &[
+            ("python", "app.py"),
+            ("uvicorn", "app:app --reload --port {port}"),
+        ],
+    },
+    PythonApp {
+        tech: "starlette",
+        name: "Starlette",
+        port: 8000,
+        cors: true,
+        dependencies: &[
+            ("starlette", ">=0.37"),
+            ("uvicorn[standard]", ">=0.24,<1.0"),
+        ],
+        files: &[],
+        run: &[
+            ("python", "app.py"),
+            ("uvicorn", "app:app --reload --port {port}"),
+        ],
+    },
+    PythonApp {
+        tech: "sanic",
+        name: "Sanic",
+        port: 8000,
+        cors: false,
+        dependencies: &[("sanic", ">=23.12")],
+        files: &[],
+        run: &[
+            ("python", "app.py"),
+            ("sanic", "app:app --dev --port {port}"),
+        ],
+    },
+    PythonApp {
+        tech: "quart",
+        name: "Quart",
+        port: 5000,
+        cors: false,
+        dependencies: &[("quart", ">=0.19")],
+        files: &[],
+        run: &[
+            ("python", "app.py"),
+            ("hypercorn", "app:app --reload --bind 0.0.0.0:{port}"),
+        ],
+    },
+    PythonApp {
+        tech: "streamlit",
+        name: "Streamlit",
+        port: 8501,
+        cors: false,
+        dependencies: &[("streamlit", ">=1.30")],
+        files: &[("config.toml", ".streamlit/config.toml")],
+        run: &[("streamlit", "run app.py")],
+    },
+    PythonApp {
+        tech: "gradio",
+        name: "Gradio",
+        port: 7860,
+        cors: false,
+        dependencies: &[("gradio", ">=4.0")],
+        files: &[],
+        run: &[("python", "app.py"), ("gradio", "app.py")],
+    },
+];
+
+/// The single-file app scaffold for `tech`, if it has one.
+fn python_app(tech: &str) -> Option<&'static PythonApp> {
+    PYTHON_APPS
+        .iter()
+        .find(|app| app.tech.eq_ignore_ascii_case(tech))
+}
+
+/// Data-science and ML packages; components built on them get a conda env
+/// by default when conda or mamba is installed.
+const DATA_SCIENCE_PACKAGES: &[&str] = &[
+    "numpy",
+    "pandas",
+    "matplotlib",
+    "scipy",
+    "scikit-learn",
+    "sklearn",
+    "tensorflow",
+    "torch",
+    "pytorch",
+    "keras",
+    "opencv",
+    "jupyter",
+];
+
+/// Data-science packages whose PyPI distribution or import name differs
+/// from what they are commonly called: (tech, distribution, import).
+const DATA_SCIENCE_NAMES: &[(&str, &str, &str)] = &[
+    ("pytorch", "torch", "torch"),
+    ("sklearn", "scikit-learn", "sklearn"),
+    ("scikit-learn", "scikit-learn", "sklearn"),
+    ("opencv", "opencv-python", "cv2"),
+    ("opencv-python", "opencv-python", "cv2"),
+    ("jupyter", "jupyter", ""),
+];
+
+/// What the manifest records about a scaffolded Python component.
+#[derive(Default)]
+pub struct ScaffoldedPython {
+    /// Tool managing the component's environment
+    pub tool: Option<PyTool>,
+    /// Named conda env the component runs in
+    pub conda_env: Option<String>,
+}
+
+pub fn is_data_science_package(tech: &str) -> bool {
+    let tech = tech.to_lowercase();
+    DATA_SCIENCE_PACKAGES.iter().any(|&pkg| tech.contains(pkg))
+}
+
+/// Scaffold a Python component. Its environment is managed by the tool named
+/// in the spec's `py-tool` option, else `tool`, else conda for data-science
+/// packages and uv for everything else, when installed.
+pub fn scaffold_python_project(
+    project_dir: &Path,
+    component_name: &str,
+    spec: &TechSpec,
+    tool: Option<PyTool>,
+    answers: &BTreeMap<String, String>,
+) -> io::Result<ScaffoldedPython> {
+    let tech = spec.name.as_str();
+    let version = spec.version.as_deref();
+    let component_dir = project_dir.join(component_name);
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("myproject");
+
+    // Check if Python is installed
+    let python_cmd = match check_python() {
+        Some(cmd) => {
+            println!("→ Found Python: {}", cmd);
+            cmd
+        }
+        None => {
+            eprintln!("⚠️  Python not found! Creating basic directory structure only.");
+            eprintln!("   Install Python to enable full scaffolding.");
+            fs::create_dir_all(&component_dir)?;
+            return Ok(ScaffoldedPython::default());
+        }
+    };
+
+    let tool = match spec.option("py-tool") {
+        Some(name) => match PyTool::parse(name) {
+            Some(tool) => Some(tool),
+            None => {
+                eprintln!("⚠️  Unknown Python tool '{}', ignoring it", name);
+                tool
+            }
+        },
+        None => tool,
+    };
+
+    // Packages go into the component's own environment, not into whatever
+    // site-packages the system interpreter uses
+    fs::create_dir_all(&component_dir)?;
+    let tool = tool.unwrap_or_else(|| {
+        is_data_science_package(tech)
+            .then(PyTool::detect_conda)
+            .flatten()
+            .unwrap_or_else(PyTool::detect)
+    });
+    let mut env = PythonEnv::setup(
+        tool,
+        &component_dir,
+        &format!("{}-{}", project_name, component_name),
+        &python_cmd,
+        spec.option("python"),
+    )?;
+
+    let mut context = TemplateContext::new(project_name, component_name);
+    if let Some(version) = python_version(&env.python) {
+        context.set_version("python", &version);
+    }
+    let mut declared = variables(tech);
+    // Libraries without a scaffold of their own get the generic package
+    if declared.is_empty() {
+        declared.push(title_var());
+    }
+    vars::resolve(&declared, None, answers, false, &mut context)?;
+
+    match tech.to_lowercase().as_str() {
+        "django" => scaffold_django(project_dir, component_name, &env, version, &context),
+        "flask" => scaffold_flask(&component_dir, &env, version, &mut context),
+        "fastapi" => scaffold_fastapi(&component_dir, &env, version, &mut context),
+        "pyramid" => scaffold_pyramid(&component_dir, &env, version, &mut context),
+        "python-cli" => scaffold_python_cli(&component_dir, &mut env, version, &mut context),
+        tech if let Some(app) = python_app(tech) => {
+            scaffold_python_app(&component_dir, &env, app, version, &mut context)
+        }
+        _ if is_data_science_package(tech) => {
+            scaffold_ml(&component_dir, &mut env, tech, version, &mut context)
+        }
+        _ => scaffold_generic_python(&component_dir, &mut env, tech, version, &mut context),
+    }?;
+
+    Ok(ScaffoldedPython {
+        tool: Some(env.tool),
+        conda_env: env.conda_env().map(|name| name.to_string()),
+    })
+}
+
+fn check_python() -> Option<String> {
+    is_installed(DEFAULT_EXECUTABLES.python)
+}
+
+fn python_version(python_cmd: &str) -> Option<String> {
+    let output = Command::new(python_cmd).arg("--version").output().ok()?;
+    let version = String::from_utf8_lossy(&output.stdout);
+    version
+        .trim()
+        .strip_prefix("Python ")
+        .map(|version| version.to_string())
+}
+
+fn scaffold_django(
+    project_dir: &Path,
+    component_name: &str,
+    env: &PythonEnv,
+    version: Option<&str>,
+    context: &TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding Django project");
+
+    let component_dir = project_dir.join(component_name);
+    fs::create_dir_all(&component_dir)?;
+
+    let drf = context.is_enabled("drf");
+    let mut dependencies = vec![
+        Dependency::new("Django", version, ">=4.2"),
+        Dependency::new("django-cors-headers", None, ">=4.0"),
+        Dependency::new("django-environ", None, ">=0.10"),
+    ];
+    if drf {
+        dependencies.push(Dependency::new("djangorestframework", None, ">=3.15"));
+    }
+    let versions = env.add(&dependencies)?;
+
+    // Check if Django is installed
+    if !versions.contains_key("Django") {
+        println!("→ Creating basic directory without Django scaffolding");
+        return Ok(());
+    }
+
+    // Use the project name for Django project, not the component name
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("myproject");
+    let app_name = context
+        .get("app")
+        .and_then(|app| app.as_str())
+        .unwrap_or("core")
+        .to_string();
+
+    // Generate straight into the component directory, which already holds
+    // the environment, using the django-admin of that environment
+    println!(
+        "→ Running django-admin startproject {} in {}",
+        project_name,
+        component_dir.display()
+    );
+    django_admin(env, &component_dir, &["startproject", project_name, "."])?;
+    println!("→ Running django-admin startapp {}", app_name);
+    django_admin(env, &component_dir, &["startapp", &app_name])?;
+
+    // One settings module per environment, all reading from .env
+    let project_package = component_dir.join(project_name);
+    let settings_dir = project_package.join("settings");
+    fs::create_dir_all(&settings_dir)?;
+    fs::remove_file(project_package.join("settings.py"))?;
+    fs::write(settings_dir.join("__init__.py"), "")?;
+    for file in ["base.py", "dev.py", "prod.py"] {
+        template::write("django", file, &settings_dir.join(file), context)?;
+    }
+    use_settings(&component_dir.join("manage.py"), project_name, "dev")?;
+    for file in ["wsgi.py", "asgi.py"] {
+        use_settings(&project_package.join(file), project_name, "prod")?;
+    }
+
+    template::write(
+        "django",
+        "urls.py",
+        &project_package.join("urls.py"),
+        context,
+    )?;
+    let app_dir = component_dir.join(&app_name);
+    template::write("django", "app_urls.py", &app_dir.join("urls.py"), context)?;
+    template::write("django", "views.py", &app_dir.join("views.py"), context)?;
+    if drf {
+        template::write(
+            "django",
+            "serializers.py",
+            &app_dir.join("serializers.py"),
+            context,
+        )?;
+    }
+
+    let mut env_context = TemplateContext::new(project_name, component_name);
+    env_context.set("secret_key", secret_key(&env.python));
+    template::write("django", "env", &component_dir.join(".env"), &env_context)?;
+    env_context.set("secret_key", "change-me");
+    template::write(
+        "django",
+        "env",
+        &component_dir.join(".env.example"),
+        &env_context,
+    )?;
+
+    println!(
+        "→ Django project '{}' scaffolded successfully as '{}'",
+        project_name, component_name
+    );
+    println!(
+        "   Files: manage.py, {}/settings/{{base,dev,prod}}.py, {}/, .env, {}",
+        project_name,
+        app_name,
+        env.dependency_files()
+    );
+    println!(
+        "   - Run: cd {} && {} manage.py migrate && {} manage.py runserver",
+        component_name,
+        env.run_cmd("python"),
+        env.run_cmd("python")
+    );
+    if drf {
+        println!("   - API: http://localhost:8000/api/hello/");
+    }
+
+    Ok(())
+}
+
+/// Run `django-admin` with `args` from the component's environment.
+fn django_admin(env: &PythonEnv, component_dir: &Path, args: &[&str]) -> io::Result<()> {
+    let status = Command::new(&env.python)
+        .args(["-m", "django"])
+        .args(args)
+        .current_dir(component_dir)
+        .status()?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!("django-admin {} failed", args[0])));
+    }
+    Ok(())
+}
+
+/// Point the default `DJANGO_SETTINGS_MODULE` in a generated file at the
+/// `settings.<module>` of the split settings package.
+fn use_settings(file: &Path, project_name: &str, module: &str) -> io::Result<()> {
+    let content = fs::read_to_string(file)?;
+    let old = format!("{}.settings", project_name);
+    let new = format!("{}.settings.{}", project_name, module);
+    let content = content
+        .replace(&format!("'{}'", old), &format!("'{}'", new))
+        .replace(&format!("\"{}\"", old), &format!("\"{}\"", new));
+    fs::write(file, content)
+}
+
+/// A fresh `SECRET_KEY`, free of the `$` that django-environ would expand.
+fn secret_key(python_cmd: &str) -> String {
+    Command::new(python_cmd)
+        .args(["-c", "import secrets; print(secrets.token_urlsafe(50))"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "change-me".to_string())
+}
+
+fn scaffold_flask(
+    component_dir: &Path,
+    env: &PythonEnv,
+    version: Option<&str>,
+    context: &mut TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding Flask application");
+
+    fs::create_dir_all(component_dir)?;
+
+    // Check and install Flask
+    let factory = context.is_enabled("factory");
+    let mut dependencies = vec![
+        Dependency::new("Flask", version, ">=2.3"),
+        Dependency::new("Flask-CORS", None, ">=4.0"),
+        Dependency::new("python-dotenv", None, ">=1.0"),
+    ];
+    if factory {
+        dependencies.push(Dependency::new("gunicorn", None, ">=22.0"));
+        dependencies.push(Dependency::new("pytest", None, ">=8.0"));
+    }
+    let versions = env.add(&dependencies)?;
+    let flask_installed = versions.contains_key("Flask");
+    if let Some(flask_version) = versions.get("Flask") {
+        context.set_version("flask", flask_version);
+    }
+    let component_name = component_dir.file_name().unwrap().to_str().unwrap();
+
+    if factory {
+        let app_dir = component_dir.join("app");
+        let tests_dir = component_dir.join("tests");
+        fs::create_dir_all(&app_dir)?;
+        fs::create_dir_all(&tests_dir)?;
+        template::write("flask", "factory.py", &app_dir.join("__init__.py"), context)?;
+        template::write("flask", "config.py", &app_dir.join("config.py"), context)?;
+        template::write("flask", "api.py", &app_dir.join("api.py"), context)?;
+        template::write("flask", "wsgi.py", &component_dir.join("wsgi.py"), context)?;
+        // Makes pytest put the component, and so `app`, on sys.path
+        fs::write(tests_dir.join("__init__.py"), "")?;
+        template::write(
+            "flask",
+            "conftest.py",
+            &tests_dir.join("conftest.py"),
+            context,
+        )?;
+        template::write(
+            "flask",
+            "test_api.py",
+            &tests_dir.join("test_api.py"),
+            context,
+        )?;
+        fs::write(
+            component_dir.join(".env"),
+            "FLASK_APP=app\nFLASK_CONFIG=development\nFLASK_DEBUG=True\n",
+        )?;
+
+        println!("→ Flask application scaffolded");
+        println!(
+            "   Files: app/{{__init__,config,api}}.py, wsgi.py, tests/, {}, .env",
+            env.dependency_files()
+        );
+        if flask_installed {
+            println!(
+                "   - Run: cd {} && {} run --port {}",
+                component_name,
+                env.run_cmd("flask"),
+                context
+                    .get("port")
+                    .map(|port| port.to_string())
+                    .unwrap_or_default()
+            );
+            println!(
+                "   - Serve: cd {} && FLASK_CONFIG=production {} wsgi:app",
+                component_name,
+                env.run_cmd("gunicorn")
+            );
+            println!(
+                "   - Test: cd {} && {}",
+                component_name,
+                env.run_cmd("pytest")
+            );
+        }
+        return Ok(());
+    }
+
+    // Create app.py
+    template::write("flask", "app.py", &component_dir.join("app.py"), context)?;
+
+    // Create .env file
+    let env_file = component_dir.join(".env");
+    let env_content = "FLASK_ENV=development\nFLASK_DEBUG=True\nFLASK_APP=app.py\n";
+    fs::write(env_file, env_content)?;
+
+    println!("→ Flask application scaffolded");
+    println!("   Files: app.py, {}, .env", env.dependency_files());
+    if flask_installed {
+        println!(
+            "   - Run: cd {} && {} app.py",
+            component_name,
+            env.run_cmd("python")
+        );
+    }
+
+    Ok(())
+}
+
+fn scaffold_fastapi(
+    component_dir: &Path,
+    env: &PythonEnv,
+    version: Option<&str>,
+    context: &mut TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding FastAPI application");
+
+    fs::create_dir_all(component_dir)?;
+
+    // A database only comes with the package layout
+    let database = context.is_enabled("database");
+    let routers = database || context.is_enabled("routers");
+
+    // Check and install FastAPI and uvicorn
+    let mut dependencies = vec![
+        Dependency::new("fastapi", version, ">=0.104,<1.0"),
+        Dependency::new("uvicorn[standard]", None, ">=0.24,<1.0"),
+        Dependency::new("python-multipart", None, ">=0.0.6"),
+    ];
+    if routers {
+        dependencies.push(Dependency::new("pydantic-settings", None, ">=2.0"));
+        dependencies.push(Dependency::new("httpx", None, ">=0.27"));
+        dependencies.push(Dependency::new("pytest", None, ">=8.0"));
+    }
+    if database {
+        dependencies.push(Dependency::new("SQLAlchemy", None, ">=2.0"));
+        dependencies.push(Dependency::new("alembic", None, ">=1.13"));
+    }
+    let versions = env.add(&dependencies)?;
+    let installed = versions.contains_key("fastapi") && versions.contains_key("uvicorn[standard]");
+    if let Some(fastapi_version) = versions.get("fastapi") {
+        context.set_version("fastapi", fastapi_version);
+    }
+
+    if routers {
+        context.set("database", database);
+        write_fastapi_package(component_dir, database, context)?;
+    } else {
+        // Create main.py
+        template::write(
+            "fastapi",
+            "main.py",
+            &component_dir.join("main.py"),
+            context,
+        )?;
+    }
+
+    let component_name = component_dir.file_name().unwrap().to_str().unwrap();
+    println!("→ FastAPI application scaffolded");
+    if routers {
+        println!(
+            "   Files: main.py, app/{{main,config}}.py, app/api/routers/, tests/, {}, .env",
+            env.dependency_files()
+        );
+    } else {
+        println!("   Files: main.py, {}", env.dependency_files());
+    }
+    if installed {
+        println!(
+            "   - Run: cd {} && {} main.py",
+            component_name,
+            env.run_cmd("python")
+        );
+        println!(
+            "   - Or: cd {} && {} main:app --reload",
+            component_name,
+            env.run_cmd("uvicorn")
+        );
+        if routers {
+            println!(
+                "   - Test: cd {} && {}",
+                component_name,
+                env.run_cmd("pytest")
+            );
+        }
+        if database {
+            println!(
+                "   - Migrations: cd {} && {} revision --autogenerate -m init && {} upgrade head",
+                component_name,
+                env.run_cmd("alembic"),
+                env.run_cmd("alembic")
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the package layout: the app in `app/`, its routes in
+/// `app/api/routers/`, settings from `.env`, tests, and optionally the
+/// SQLAlchemy session and Alembic migrations. A root `main.py` keeps
+/// `python main.py` and `uvicorn main:app` working.
+fn write_fastapi_package(
+    component_dir: &Path,
+    database: bool,
+    context: &TemplateContext,
+) -> io::Result<()> {
+    let app_dir = component_dir.join("app");
+    let routers_dir = app_dir.join("api").join("routers");
+    let tests_dir = component_dir.join("tests");
+    fs::create_dir_all(&routers_dir)?;
+    fs::create_dir_all(&tests_dir)?;
+
+    for package in [&app_dir, &app_dir.join("api"), &routers_dir, &tests_dir] {
+        fs::write(package.join("__init__.py"), "")?;
+    }
+    let files = [
+        ("app_main.py", app_dir.join("main.py")),
+        ("config.py", app_dir.join("config.py")),
+        ("health.py", routers_dir.join("health.py")),
+        ("hello.py", routers_dir.join("hello.py")),
+        ("run.py", component_dir.join("main.py")),
+        ("test_api.py", tests_dir.join("test_api.py")),
+        ("env", component_dir.join(".env")),
+    ];
+    for (file, dest) in &files {
+        template::write("fastapi", file, dest, context)?;
+    }
+
+    if database {
+        let alembic_dir = component_dir.join("alembic");
+        fs::create_dir_all(alembic_dir.join("versions"))?;
+        fs::write(alembic_dir.join("versions").join(".gitkeep"), "")?;
+        let files = [
+            ("db.py", app_dir.join("db.py")),
+            ("alembic.ini", component_dir.join("alembic.ini")),
+            ("alembic_env.py", alembic_dir.join("env.py")),
+            ("script.py.mako", alembic_dir.join("script.py.mako")),
+        ];
+        for (file, dest) in &files {
+            template::write("fastapi", file, dest, context)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn scaffold_pyramid(
+    component_dir: &Path,
+    env: &PythonEnv,
+    version: Option<&str>,
+    context: &mut TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding Pyramid application");
+
+    fs::create_dir_all(component_dir)?;
+
+    // Check and install Pyramid
+    let versions = env.add(&[
+        Dependency::new("pyramid", version, ">=2.0"),
+        Dependency::new("waitress", None, ">=2.1"),
+    ])?;
+    let pyramid_installed = versions.contains_key("pyramid");
+    if let Some(pyramid_version) = versions.get("pyramid") {
+        context.set_version("pyramid", pyramid_version);
+    }
+
+    // Create app.py
+    template::write("pyramid", "app.py", &component_dir.join("app.py"), context)?;
+
+    println!("→ Pyramid application scaffolded");
+    println!("   Files: app.py, {}", env.dependency_files());
+    if pyramid_installed {
+        println!(
+            "   - Run: cd {} && {} app.py",
+            component_dir.file_name().unwrap().to_str().unwrap(),
+            env.run_cmd("python")
+        );
+    }
+
+    Ok(())
+}
+
+fn scaffold_python_app(
+    component_dir: &Path,
+    env: &PythonEnv,
+    app: &PythonApp,
+    version: Option<&str>,
+    context: &mut TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding {} application", app.name);
+
+    fs::create_dir_all(component_dir)?;
+
+    let dependencies: Vec<Dependency> = app
+        .dependencies
+        .iter()
+        .enumerate()
+        .map(|(i, (package, fallback))| {
+            // A requested version applies to the framework only
+            Dependency::new(package, if i == 0 { version } else { None }, fallback)
+        })
+        .collect();
+    let versions = env.add(&dependencies)?;
+    let framework = app.dependencies[0].0;
+    let installed = versions.contains_key(framework);
+    if let Some(framework_version) = versions.get(framework) {
+        context.set_version(app.tech, framework_version);
+    }
+
+    template::write(app.tech, "app.py", &component_dir.join("app.py"), context)?;
+    let mut files = vec!["app.py".to_string()];
+    for (file, dest) in app.files {
+        let dest_path = component_dir.join(dest);
+        if let Some(parent) = dest_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        template::write(app.tech, file, &dest_path, context)?;
+        files.push(dest.to_string());
+    }
+
+    println!("→ {} application scaffolded", app.name);
+    println!("   Files: {}, {}", files.join(", "), env.dependency_files());
+    if installed {
+        let component_name = component_dir.file_name().unwrap().to_str().unwrap();
+        let port = context
+            .get("port")
+            .map(|port| port.to_string())
+            .unwrap_or_default();
+        for (i, (executable, args)) in app.run.iter().enumerate() {
+            println!(
+                "   - {}: cd {} && {} {}",
+                if i == 0 { "Run" } else { "Or" },
+                component_name,
+                env.run_cmd(executable),
+                args.replace("{port}", &port)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Scaffold an installable src-layout package with a console script,
+/// pytest tests and ruff/mypy configuration.
+fn scaffold_generic_python(
+    component_dir: &Path,
+    env: &mut PythonEnv,
+    tech: &str,
+    version: Option<&str>,
+    context: &mut TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding Python package for '{}'", tech);
+
+    let component_name = component_dir.file_name().unwrap().to_str().unwrap();
+    let package = write_package(component_dir, tech, context)?;
+
+    // Check if the specified package exists
+    let dependencies = if !tech.is_empty() && tech != "python" {
+        vec![Dependency::new(tech, version, "")]
+    } else {
+        Vec::new()
+    };
+    let script = package.script.clone();
+    let module = package.name.clone();
+    env.package = Some(package);
+    env.add(&dependencies)?;
+
+    println!("→ Python package scaffolded");
+    println!(
+        "   Files: src/{}/, tests/, {}",
+        module,
+        env.dependency_files()
+    );
+    println!(
+        "   - Run: cd {} && {}",
+        component_name,
+        env.run_cmd(&script)
+    );
+    println!(
+        "   - Or: cd {} && {} -m {}",
+        component_name,
+        env.run_cmd("python"),
+        module
+    );
+    println!(
+        "   - Test: cd {} && {}",
+        component_name,
+        env.run_cmd("pytest")
+    );
+
+    Ok(())
+}
+
+/// Scaffold a command-line tool: a src-layout package whose console script
+/// has subcommands, `--version` from the package metadata, shell completion
+/// and `CliRunner` tests. The framework is Typer or Click, per `framework`.
+fn scaffold_python_cli(
+    component_dir: &Path,
+    env: &mut PythonEnv,
+    version: Option<&str>,
+    context: &mut TemplateContext,
+) -> io::Result<()> {
+    let framework = match context.get("framework").and_then(|value| value.as_str()) {
+        Some("click") => "click",
+        _ => "typer",
+    };
+    println!("→ Scaffolding Python CLI with {}", framework);
+
+    let component_name = component_dir.file_name().unwrap().to_str().unwrap();
+    let package = Package::for_component(component_name);
+    context
+        .set("package", package.name.as_str())
+        .set("script", package.script.as_str())
+        .set("distribution", env.name())
+        .set(
+            "complete_var",
+            format!(
+                "_{}_COMPLETE",
+                package.script.to_uppercase().replace('-', "_")
+            ),
+        );
+
+    let cli = format!("cli_{}.py", framework);
+    let test = format!("test_{}.py", framework);
+    write_sources(
+        component_dir,
+        &package,
+        "python-cli",
+        &[
+            ("__init__.py", "__init__.py"),
+            ("__main__.py", "__main__.py"),
+            (&cli, "cli.py"),
+        ],
+        &[(&test, "test_cli.py")],
+        context,
+    )?;
+
+    let (requirement, fallback) = match framework {
+        "click" => ("click", ">=8.1"),
+        _ => ("typer", ">=0.12"),
+    };
+    let script = package.script.clone();
+    let module = package.name.clone();
+    env.package = Some(package);
+    let versions = env.add(&[Dependency::new(requirement, version, fallback)])?;
+    if let Some(framework_version) = versions.get(requirement) {
+        context.set_version(framework, framework_version);
+    }
+
+    println!("→ Python CLI scaffolded");
+    println!(
+        "   Files: src/{}/cli.py, tests/test_cli.py, {}",
+        module,
+        env.dependency_files()
+    );
+    println!(
+        "   - Run: cd {} && {} hello --help",
+        component_name,
+        env.run_cmd(&script)
+    );
+    if framework == "click" {
+        println!("   - Completion: eval \"$({} completion bash)\"", script);
+    } else {
+        println!("   - Completion: {} --install-completion", script);
+    }
+    println!(
+        "   - Test: cd {} && {}",
+        component_name,
+        env.run_cmd("pytest")
+    );
+
+    Ok(())
+}
+
+/// Scaffold a data-science project: a src-layout package next to `data/`,
+/// `models/` and `notebooks/`, with a Jupyter kernel for the environment.
+fn scaffold_ml(
+    component_dir: &Path,
+    env: &mut PythonEnv,
+    tech: &str,
+    version: Option<&str>,
+    context: &mut TemplateContext,
+) -> io::Result<()> {
+    println!("→ Scaffolding data-science project for '{}'", tech);
+
+    let component_name = component_dir.file_name().unwrap().to_str().unwrap();
+    let (distribution, import) = DATA_SCIENCE_NAMES
+        .iter()
+        .find(|(name, _, _)| name.eq_ignore_ascii_case(tech))
+        .map_or((tech, tech), |&(_, distribution, import)| {
+            (distribution, import)
+        });
+    let package = write_package(component_dir, tech, context)?;
+    template::write(
+        "ml",
+        "paths.py",
+        &component_dir
+            .join("src")
+            .join(&package.name)
+            .join("paths.py"),
+        context,
+    )?;
+
+    for dir in ["data/raw", "data/processed", "models"] {
+        let dir = component_dir.join(dir);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(".gitkeep"), "")?;
+    }
+    let notebooks_dir = component_dir.join("notebooks");
+    fs::create_dir_all(&notebooks_dir)?;
+    context.set("import", import).set("kernel", env.name());
+    template::write(
+        "ml",
+        "exploration.ipynb",
+        &notebooks_dir.join("01-exploration.ipynb"),
+        context,
+    )?;
+    write_data_ignores(component_dir)?;
+
+    let module = package.name.clone();
+    env.package = Some(package);
+    let versions = env.add(&[
+        Dependency::new(distribution, version, ""),
+        Dependency::new("ipykernel", None, ">=6.0"),
+    ])?;
+    if let Some(tech_version) = versions.get(distribution) {
+        context.set_version(tech, tech_version);
+    }
+    let kernel = versions.contains_key("ipykernel") && register_kernel(env)?;
+
+    println!("→ Data-science project scaffolded");
+    println!(
+        "   Files: src/{}/, tests/, notebooks/01-exploration.ipynb, data/, models/, {}",
+        module,
+        env.dependency_files()
+    );
+    if kernel {
+        println!(
+            "   - Notebooks: pick the '{}' kernel in Jupyter or your editor",
+            env.name()
+        );
+    } else {
+        println!(
+            "   - Kernel: cd {} && {} -m ipykernel install --user --name {}",
+            component_name,
+            env.run_cmd("python"),
+            env.name()
+        );
+    }
+    println!(
+        "   - Test: cd {} && {}",
+        component_name,
+        env.run_cmd("pytest")
+    );
+
+    Ok(())
+}
+
+/// Write the `src/<package>/` package and its `tests/` into `component_dir`.
+fn write_package(
+    component_dir: &Path,
+    tech: &str,
+    context: &mut TemplateContext,
+) -> io::Result<Package> {
+    let component_name = component_dir.file_name().unwrap().to_str().unwrap();
+    let package = Package::for_component(component_name);
+    context
+        .set("tech", tech)
+        .set("package", package.name.as_str())
+        .set("script", package.script.as_str());
+
+    write_sources(
+        component_dir,
+        &package,
+        "python",
+        &[
+            ("__init__.py", "__init__.py"),
+            ("__main__.py", "__main__.py"),
+        ],
+        &[("test_main.py", "test_main.py")],
+        context,
+    )?;
+
+    Ok(package)
+}
+
+/// Render the `group` templates into `src/<package>/` and `tests/`, each
+/// given as template and file name.
+fn write_sources(
+    component_dir: &Path,
+    package: &Package,
+    group: &str,
+    sources: &[(&str, &str)],
+    tests: &[(&str, &str)],
+    context: &TemplateContext,
+) -> io::Result<()> {
+    // The sources have to be in place before the package is installed
+    let package_dir = component_dir.join("src").join(&package.name);
+    let tests_dir = component_dir.join("tests");
+    fs::create_dir_all(&package_dir)?;
+    fs::create_dir_all(&tests_dir)?;
+    for (template_file, file) in sources {
+        template::write(group, template_file, &package_dir.join(file), context)?;
+    }
+    // Tells mypy and other type checkers that the package is typed
+    fs::write(package_dir.join("py.typed"), "")?;
+    for (template_file, file) in tests {
+        template::write(group, template_file, &tests_dir.join(file), context)?;
+    }
+
+    Ok(())
+}
+
+/// Keep datasets out of git, leaving room for DVC pointer files, and send
+/// model binaries through Git LFS.
+fn write_data_ignores(component_dir: &Path) -> io::Result<()> {
+    let mut gitignore = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(component_dir.join(".gitignore"))?;
+    gitignore.write_all(
+        b"\n# Data is versioned with DVC (`dvc add data/raw`), not git\n\
+          /data/raw/*\n\
+          /data/processed/*\n\
+          !/data/*/.gitkeep\n\
+          !/data/**/*.dvc\n\
+          .ipynb_checkpoints/\n",
+    )?;
+
+    fs::write(
+        component_dir.join(".gitattributes"),
+        "# Model binaries are stored with Git LFS\n\
+         models/**/*.pt filter=lfs diff=lfs merge=lfs -text\n\
+         models/**/*.pth filter=lfs diff=lfs merge=lfs -text\n\
+         models/**/*.h5 filter=lfs diff=lfs merge=lfs -text\n\
+         models/**/*.keras filter=lfs diff=lfs merge=lfs -text\n\
+         models/**/*.onnx filter=lfs diff=lfs merge=lfs -text\n\
+         models/**/*.pkl filter=lfs diff=lfs merge=lfs -text\n\
+         models/**/*.joblib filter=lfs diff=lfs merge=lfs -text\n\
+         models/**/*.safetensors filter=lfs diff=lfs merge=lfs -text\n",
+    )
+}
+
+/// Register a Jupyter kernel for the component's environment, named after
+/// it. Returns whether the kernel was registered.
+fn register_kernel(env: &PythonEnv) -> io::Result<bool> {
+    println!("→ Registering Jupyter kernel '{}'", env.name());
+    let status = Command::new(&env.python)
+        .args(["-m", "ipykernel", "install", "--user", "--name"])
+        .arg(env.name())
+        .arg("--display-name")
+        .arg(format!("Python ({})", env.name()))
+        .status()?;
+
+    if !status.success() {
+        eprintln!("⚠️  Could not register the Jupyter kernel");
+    }
+    Ok(status.success())
+}
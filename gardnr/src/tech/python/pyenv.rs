@@ -0,0 +1,132 @@
+use crate::utils::is_installed;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Whether pyenv and the pyenv-virtualenv plugin are both available.
+pub fn is_available() -> bool {
+    if is_installed(&["pyenv"]).is_none() {
+        return false;
+    }
+
+    Command::new("pyenv")
+        .arg("commands")
+        .output()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .any(|command| command.trim() == "virtualenv")
+        })
+        .unwrap_or(false)
+}
+
+/// Create (or reuse) the pyenv virtualenv `env_name` on the newest installed
+/// Python matching `requested`, installing that Python first if needed, and
+/// select it for `component_dir` through `.python-version`. Returns the
+/// environment's interpreter.
+pub fn setup_virtualenv(
+    component_dir: &Path,
+    env_name: &str,
+    requested: &str,
+) -> io::Result<PathBuf> {
+    let prefix = match prefix(env_name) {
+        Some(prefix) => {
+            println!("→ Using existing pyenv virtualenv {}", env_name);
+            prefix
+        }
+        None => {
+            let version = match installed_version(requested) {
+                Some(version) => version,
+                None => install(requested)?,
+            };
+
+            println!("→ pyenv virtualenv {} {}", version, env_name);
+            let status = Command::new("pyenv")
+                .args(["virtualenv", &version, env_name])
+                .status()?;
+
+            if !status.success() {
+                return Err(io::Error::other("pyenv virtualenv failed"));
+            }
+            prefix(env_name)
+                .ok_or_else(|| io::Error::other(format!("pyenv has no prefix for {}", env_name)))?
+        }
+    };
+
+    // Same as `pyenv local <env_name>`
+    println!("→ pyenv local {} in {}", env_name, component_dir.display());
+    fs::write(
+        component_dir.join(".python-version"),
+        format!("{}\n", env_name),
+    )?;
+
+    Ok(prefix.join("bin").join("python"))
+}
+
+/// Interpreter of the newest installed Python matching `requested`, if any.
+pub fn interpreter(requested: &str) -> Option<PathBuf> {
+    let version = installed_version(requested)?;
+    Some(prefix(&version)?.join("bin").join("python"))
+}
+
+/// Newest installed Python version that `requested` is a prefix of, e.g.
+/// `3.12` → `3.12.4`.
+fn installed_version(requested: &str) -> Option<String> {
+    let output = Command::new("pyenv")
+        .args(["versions", "--bare", "--skip-aliases"])
+        .output()
+        .ok()?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| line.trim())
+        // Virtualenvs are listed as `<version>/envs/<name>`
+        .filter(|version| !version.contains('/'))
+        .filter(|version| *version == requested || version.starts_with(&format!("{}.", requested)))
+        .max_by_key(|version| version_key(version))
+        .map(|version| version.to_string())
+}
+
+fn install(requested: &str) -> io::Result<String> {
+    println!("→ pyenv install {} (this can take a while)", requested);
+    let status = Command::new("pyenv")
+        .args(["install", "--skip-existing", requested])
+        .status()?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "pyenv install {} failed",
+            requested
+        )));
+    }
+    installed_version(requested).ok_or_else(|| {
+        io::Error::other(format!(
+            "pyenv installed {} but it is not listed",
+            requested
+        ))
+    })
+}
+
+fn prefix(version: &str) -> Option<PathBuf> {
+    let output = Command::new("pyenv")
+        .args(["prefix", version])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+    let prefix = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!prefix.is_empty()).then(|| PathBuf::from(prefix))
+}
+
+/// Numeric sort key for `3.12.4`-style versions; anything non-numeric
+/// (pre-releases, other implementations) sorts first.
+fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
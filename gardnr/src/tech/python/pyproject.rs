@@ -0,0 +1,253 @@
+use super::env::{Dependency, PyTool, VENV_DIR};
+
+/// An importable package under `src/`, built and installed into the
+/// component's environment together with a console script.
+pub struct Package {
+    /// Import name, the package lives in `src/<name>/`
+    pub name: String,
+    /// Console script running the package's `main()`
+    pub script: String,
+}
+
+impl Package {
+    /// The package a component is scaffolded as, named after the component.
+    pub fn for_component(component_name: &str) -> Package {
+        let name: String = component_name
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let name = if name.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("app_{}", name)
+        } else {
+            name
+        };
+
+        Package {
+            script: name.replace('_', "-"),
+            name,
+        }
+    }
+
+    fn entry_point(&self) -> String {
+        format!("{}.__main__:main", self.name)
+    }
+}
+
+/// Development tools every package is set up with and configured for.
+pub fn dev_dependencies() -> Vec<Dependency> {
+    vec![
+        Dependency::new("pytest", None, ">=8.0"),
+        Dependency::new("ruff", None, ">=0.5"),
+        Dependency::new("mypy", None, ">=1.10"),
+    ]
+}
+
+/// Render the `pyproject.toml` of a component: `[tool.poetry]` for Poetry,
+/// a PEP 621 `[project]` for everything else.
+///
+/// Without a `package`, components are applications rather than libraries,
+/// so each tool is told not to build or install the project itself. With
+/// one, the project is built from `src/` with a console script, and gets
+/// the dev tools and their configuration.
+pub fn render(
+    tool: PyTool,
+    name: &str,
+    requires_python: &str,
+    dependencies: &[Dependency],
+    package: Option<&Package>,
+) -> String {
+    let mut content = match tool {
+        PyTool::Poetry => render_poetry(name, requires_python, dependencies, package),
+        _ => render_pep621(tool, name, requires_python, dependencies, package),
+    };
+    if package.is_some() {
+        content.push_str(TOOL_CONFIG);
+    }
+    content
+}
+
+/// ruff, mypy and pytest configuration for src-layout packages.
+const TOOL_CONFIG: &str = "\n[tool.ruff]\n\
+                           src = [\"src\", \"tests\"]\n\
+                           \n\
+                           [tool.ruff.lint]\n\
+                           select = [\"E\", \"F\", \"I\", \"B\", \"UP\"]\n\
+                           \n\
+                           [tool.mypy]\n\
+                           strict = true\n\
+                           mypy_path = \"src\"\n\
+                           files = [\"src\", \"tests\"]\n\
+                           \n\
+                           [tool.pytest.ini_options]\n\
+                           testpaths = [\"tests\"]\n\
+                           pythonpath = [\"src\"]\n\
+                           addopts = \"-ra\"\n";
+
+fn render_poetry(
+    name: &str,
+    requires_python: &str,
+    dependencies: &[Dependency],
+    package: Option<&Package>,
+) -> String {
+    let mut content = format!(
+        "[tool.poetry]\n\
+         name = {}\n\
+         version = \"0.1.0\"\n\
+         description = \"\"\n\
+         authors = []\n",
+        quote(name)
+    );
+    match package {
+        Some(package) => content.push_str(&format!(
+            "packages = [{{ include = {}, from = \"src\" }}]\n",
+            quote(&package.name)
+        )),
+        None => content.push_str("package-mode = false\n"),
+    }
+
+    content.push_str(&format!(
+        "\n[tool.poetry.dependencies]\npython = {}\n",
+        quote(requires_python)
+    ));
+    for dependency in dependencies {
+        content.push_str(&poetry_dependency(dependency));
+    }
+
+    if let Some(package) = package {
+        content.push_str("\n[tool.poetry.group.dev.dependencies]\n");
+        for dependency in dev_dependencies() {
+            content.push_str(&poetry_dependency(&dependency));
+        }
+        content.push_str(&format!(
+            "\n[tool.poetry.scripts]\n{} = {}\n",
+            key(&package.script),
+            quote(&package.entry_point())
+        ));
+    }
+
+    content.push_str(
+        "\n[build-system]\n\
+         requires = [\"poetry-core>=1.0.0\"]\n\
+         build-backend = \"poetry.core.masonry.api\"\n",
+    );
+    content
+}
+
+fn poetry_dependency(dependency: &Dependency) -> String {
+    let extras = dependency.extras();
+    let constraint = quote(&dependency.constraint());
+    let value = if extras.is_empty() {
+        constraint
+    } else {
+        let extras: Vec<String> = extras.iter().map(|extra| quote(extra)).collect();
+        format!(
+            "{{ version = {}, extras = [{}] }}",
+            constraint,
+            extras.join(", ")
+        )
+    };
+    format!("{} = {}\n", key(dependency.distribution()), value)
+}
+
+fn render_pep621(
+    tool: PyTool,
+    name: &str,
+    requires_python: &str,
+    dependencies: &[Dependency],
+    package: Option<&Package>,
+) -> String {
+    let mut content = format!(
+        "[project]\n\
+         name = {}\n\
+         version = \"0.1.0\"\n\
+         description = \"\"\n\
+         requires-python = {}\n\
+         dependencies = {}\n",
+        quote(name),
+        quote(requires_python),
+        array(dependencies)
+    );
+
+    if let Some(package) = package {
+        content.push_str(&format!(
+            "\n[project.scripts]\n{} = {}\n",
+            key(&package.script),
+            quote(&package.entry_point())
+        ));
+        // Hatch has no dependency groups; its dev tools go into the env below
+        if tool != PyTool::Hatch {
+            content.push_str(&format!(
+                "\n[dependency-groups]\ndev = {}\n",
+                array(&dev_dependencies())
+            ));
+        }
+    }
+
+    if tool == PyTool::Pdm {
+        content.push_str(
+            "\n[build-system]\n\
+             requires = [\"pdm-backend\"]\n\
+             build-backend = \"pdm.backend\"\n",
+        );
+        if package.is_none() {
+            content.push_str("\n[tool.pdm]\ndistribution = false\n");
+        }
+        return content;
+    }
+
+    content.push_str(
+        "\n[build-system]\n\
+         requires = [\"hatchling\"]\n\
+         build-backend = \"hatchling.build\"\n",
+    );
+    if let Some(package) = package {
+        content.push_str(&format!(
+            "\n[tool.hatch.build.targets.wheel]\npackages = [{}]\n",
+            quote(&format!("src/{}", package.name))
+        ));
+    }
+    if tool == PyTool::Hatch {
+        content.push_str(&format!(
+            "\n[tool.hatch.envs.default]\npath = {}\n",
+            quote(VENV_DIR)
+        ));
+        match package {
+            Some(_) => {
+                content.push_str(&format!("dependencies = {}\n", array(&dev_dependencies())))
+            }
+            None => content.push_str("skip-install = true\n"),
+        }
+    }
+    content
+}
+
+/// A TOML array of PEP 508 requirements, one per line.
+fn array(dependencies: &[Dependency]) -> String {
+    if dependencies.is_empty() {
+        return "[]".to_string();
+    }
+    let mut array = "[\n".to_string();
+    for dependency in dependencies {
+        array.push_str(&format!("    {},\n", quote(&dependency.spec())));
+    }
+    array.push(']');
+    array
+}
+
+/// A TOML key, bare when the name allows it.
+fn key(name: &str) -> String {
+    if name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+/// A TOML basic string.
+fn quote(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
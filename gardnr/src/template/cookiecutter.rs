@@ -0,0 +1,189 @@
+use super::repo::{RenderMode, TemplateLayout};
+use super::vars::{TemplateVar, VarKind};
+use serde_json::Value as Json;
+use std::{fs, io, path::Path};
+
+/// Load a cookiecutter template rooted at `dir`, if it has a `cookiecutter.json`.
+///
+/// The rendered project is the single `{{cookiecutter.*}}` directory next to
+/// the JSON file; its contents go straight into the component directory.
+/// Hooks are not run.
+pub fn load(dir: &Path) -> io::Result<Option<TemplateLayout>> {
+    let config_path = dir.join("cookiecutter.json");
+    if !config_path.is_file() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&config_path)?;
+    let config: serde_json::Map<String, Json> = serde_json::from_str(&content)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let mut vars = Vec::new();
+    let mut copy_without_render = Vec::new();
+
+    for (name, value) in &config {
+        if name == "_copy_without_render" {
+            copy_without_render = string_list(value);
+            continue;
+        }
+
+        let var = match value {
+            Json::String(default) => TemplateVar::new(name, VarKind::String, default),
+            Json::Bool(default) => TemplateVar::new(name, VarKind::Bool, &default.to_string()),
+            Json::Number(default) if default.is_i64() => {
+                TemplateVar::new(name, VarKind::Int, &default.to_string())
+            }
+            Json::Number(default) => TemplateVar::new(name, VarKind::String, &default.to_string()),
+            Json::Array(choices) => TemplateVar::new(
+                name,
+                VarKind::Choice(string_list(&Json::Array(choices.clone()))),
+                "",
+            ),
+            // Dict variables and private settings are not prompted for
+            _ => continue,
+        };
+        let var = if name.starts_with('_') {
+            var.hidden()
+        } else {
+            var
+        };
+        vars.push(var);
+    }
+
+    let prompts = config
+        .get("__prompts__")
+        .and_then(|prompts| prompts.as_object());
+    if let Some(prompts) = prompts {
+        for var in &mut vars {
+            var.help = match prompts.get(&var.name) {
+                Some(Json::String(help)) => Some(help.clone()),
+                Some(Json::Object(help)) => help
+                    .get("__prompt__")
+                    .and_then(|help| help.as_str())
+                    .map(|help| help.to_string()),
+                _ => None,
+            };
+        }
+    }
+
+    let root = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.is_dir()
+                && path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().contains("cookiecutter"))
+                    .unwrap_or(false)
+                && path.to_string_lossy().contains("{{")
+        })
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "cookiecutter template has no {{cookiecutter.*}} directory",
+            )
+        })?;
+
+    if dir.join("hooks").is_dir() {
+        println!("→ Note: cookiecutter hooks are not run by gardnr");
+    }
+
+    Ok(Some(TemplateLayout {
+        root,
+        vars,
+        namespace: Some("cookiecutter"),
+        mode: RenderMode::All,
+        copy_without_render,
+        exclude: Vec::new(),
+        answers_file: None,
+        python_bools: true,
+    }))
+}
+
+fn string_list(value: &Json) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| match item {
+                    Json::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::{scratch_dir, write_files};
+    use super::*;
+
+    #[test]
+    fn loads_variables_prompts_and_project_directory() {
+        let dir = scratch_dir("cookiecutter-load");
+        write_files(
+            &dir,
+            &[
+                (
+                    "cookiecutter.json",
+                    r#"{
+                        "project_name": "Demo",
+                        "use_docker": false,
+                        "workers": 2,
+                        "license": ["MIT", "BSD-3-Clause"],
+                        "_private": "x",
+                        "_copy_without_render": ["*.html"],
+                        "__prompts__": {
+                            "project_name": "Project name",
+                            "license": {"__prompt__": "License", "MIT": "MIT License"}
+                        }
+                    }"#,
+                ),
+                ("{{cookiecutter.project_name}}/README.md", ""),
+            ],
+        );
+
+        let layout = load(&dir).unwrap().unwrap();
+        assert_eq!(layout.root, dir.join("{{cookiecutter.project_name}}"));
+        assert_eq!(layout.namespace, Some("cookiecutter"));
+        assert!(matches!(layout.mode, RenderMode::All));
+        assert_eq!(layout.copy_without_render, ["*.html"]);
+
+        let names: Vec<&str> = layout.vars.iter().map(|var| var.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "project_name",
+                "use_docker",
+                "workers",
+                "license",
+                "_private"
+            ]
+        );
+        let var = |name: &str| layout.vars.iter().find(|var| var.name == name).unwrap();
+        assert!(matches!(var("use_docker").kind, VarKind::Bool));
+        assert_eq!(var("use_docker").default, "false");
+        assert!(matches!(var("workers").kind, VarKind::Int));
+        assert!(
+            matches!(&var("license").kind, VarKind::Choice(choices) if choices == &["MIT", "BSD-3-Clause"])
+        );
+        assert_eq!(var("project_name").help.as_deref(), Some("Project name"));
+        assert_eq!(var("license").help.as_deref(), Some("License"));
+        assert!(!var("_private").prompt);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn needs_cookiecutter_json_and_project_directory() {
+        let dir = scratch_dir("cookiecutter-missing");
+        assert!(load(&dir).unwrap().is_none());
+
+        write_files(&dir, &[("cookiecutter.json", "{}")]);
+        assert_eq!(load(&dir).err().unwrap().kind(), io::ErrorKind::InvalidData);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
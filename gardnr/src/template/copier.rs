@@ -0,0 +1,270 @@
+use super::TemplateContext;
+use super::repo::{RenderMode, TemplateLayout};
+use super::vars::{TemplateVar, VarKind};
+use minijinja::Value;
+use std::{collections::BTreeMap, fs, io, path::Path};
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// Files copier itself never copies into the generated project.
+const DEFAULT_EXCLUDE: &[&str] = &["copier.yml", "copier.yaml", "~*", "*.py[co]", "__pycache__"];
+
+/// Where copier records the answers unless `_answers_file` says otherwise.
+const DEFAULT_ANSWERS_FILE: &str = ".copier-answers.yml";
+
+/// Load a copier template rooted at `dir`, if it has a `copier.yml`.
+///
+/// Only files carrying the `.jinja` suffix are rendered, as copier does by
+/// default. Tasks and migrations are not run.
+///
+/// Templates usually write their answers with
+/// `{{ _copier_answers|to_nice_yaml }}` into `{{ _copier_conf.answers_file }}`;
+/// both are set by [`publish_answers`] once the questions are answered.
+pub fn load(dir: &Path) -> io::Result<Option<TemplateLayout>> {
+    let config_path = ["copier.yml", "copier.yaml"]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file());
+    let Some(config_path) = config_path else {
+        return Ok(None);
+    };
+
+    let content = fs::read_to_string(&config_path)?;
+    let documents = YamlLoader::load_from_str(&content)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    let Some(config) = documents.first().and_then(|doc| doc.as_hash()) else {
+        return Ok(Some(layout(
+            dir.to_path_buf(),
+            Vec::new(),
+            Vec::new(),
+            DEFAULT_ANSWERS_FILE.to_string(),
+        )));
+    };
+
+    let mut vars = Vec::new();
+    let mut root = dir.to_path_buf();
+    let mut exclude: Vec<String> = DEFAULT_EXCLUDE.iter().map(|s| s.to_string()).collect();
+    let mut answers_file = DEFAULT_ANSWERS_FILE.to_string();
+
+    for (key, value) in config {
+        let Some(name) = key.as_str() else {
+            continue;
+        };
+
+        match name {
+            "_subdirectory" => {
+                if let Some(subdirectory) = value.as_str() {
+                    root = dir.join(subdirectory);
+                }
+            }
+            "_exclude" => exclude = string_list(value),
+            "_answers_file" => {
+                if let Some(file) = value.as_str() {
+                    answers_file = file.to_string();
+                }
+            }
+            _ if name.starts_with('_') => {}
+            _ => vars.push(question(name, value)),
+        }
+    }
+
+    Ok(Some(layout(root, vars, exclude, answers_file)))
+}
+
+/// Expose `_copier_conf` and `_copier_answers` the way copier does: the
+/// answers to `vars` as resolved into `context`, with the template's source
+/// and commit.
+pub fn publish_answers(
+    context: &mut TemplateContext,
+    vars: &[TemplateVar],
+    answers_file: &str,
+    src_path: &str,
+    commit: Option<&str>,
+) {
+    let mut answers: BTreeMap<String, Value> = BTreeMap::new();
+    if let Some(commit) = commit {
+        answers.insert("_commit".to_string(), Value::from(commit));
+    }
+    answers.insert("_src_path".to_string(), Value::from(src_path));
+    for var in vars {
+        if let Some(value) = context.get(&var.name) {
+            answers.insert(var.name.clone(), value.clone());
+        }
+    }
+
+    let mut conf = BTreeMap::new();
+    conf.insert("answers_file", answers_file);
+    conf.insert("src_path", src_path);
+    context
+        .set("_copier_conf", Value::from_serialize(&conf))
+        .set("_copier_answers", Value::from_serialize(&answers));
+}
+
+fn layout(
+    root: std::path::PathBuf,
+    vars: Vec<TemplateVar>,
+    exclude: Vec<String>,
+    answers_file: String,
+) -> TemplateLayout {
+    TemplateLayout {
+        root,
+        vars,
+        namespace: None,
+        mode: RenderMode::SuffixOnly,
+        copy_without_render: Vec::new(),
+        exclude,
+        answers_file: Some(answers_file),
+        python_bools: true,
+    }
+}
+
+/// Turn a copier question (either `name: default` or a mapping) into a variable.
+fn question(name: &str, value: &Yaml) -> TemplateVar {
+    let Some(spec) = value.as_hash() else {
+        let kind = match value {
+            Yaml::Boolean(_) => VarKind::Bool,
+            Yaml::Integer(_) => VarKind::Int,
+            _ => VarKind::String,
+        };
+        return TemplateVar::new(name, kind, &scalar(value));
+    };
+
+    let field = |key: &str| spec.get(&Yaml::String(key.to_string()));
+
+    let choices = field("choices").map(|choices| match choices {
+        // `{label: value}` choices; the value is what ends up in the answers
+        Yaml::Hash(map) => map.values().map(scalar).collect(),
+        other => string_list(other),
+    });
+    let kind = match (choices, field("type").and_then(|t| t.as_str())) {
+        (Some(choices), _) => VarKind::Choice(choices),
+        (None, Some("bool")) => VarKind::Bool,
+        (None, Some("int")) => VarKind::Int,
+        _ => VarKind::String,
+    };
+
+    TemplateVar {
+        help: field("help")
+            .and_then(|help| help.as_str())
+            .map(|s| s.to_string()),
+        validator: field("validator")
+            .and_then(|validator| validator.as_str())
+            .map(|s| s.to_string()),
+        ..TemplateVar::new(
+            name,
+            kind,
+            &field("default").map(scalar).unwrap_or_default(),
+        )
+    }
+}
+
+fn scalar(value: &Yaml) -> String {
+    match value {
+        Yaml::String(s) | Yaml::Real(s) => s.clone(),
+        Yaml::Integer(i) => i.to_string(),
+        Yaml::Boolean(b) => b.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn string_list(value: &Yaml) -> Vec<String> {
+    value
+        .as_vec()
+        .map(|items| items.iter().map(scalar).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::{scratch_dir, write_files};
+    use super::*;
+
+    #[test]
+    fn loads_questions_and_settings() {
+        let dir = scratch_dir("copier-load");
+        write_files(
+            &dir,
+            &[(
+                "copier.yml",
+                "_subdirectory: template\n\
+                 _exclude: [\"*.bak\"]\n\
+                 _answers_file: .answers.yml\n\
+                 _tasks: [\"echo skipped\"]\n\
+                 project_name: demo\n\
+                 use_docker: false\n\
+                 workers: 2\n\
+                 python:\n\
+                 \x20 type: str\n\
+                 \x20 help: Python version\n\
+                 \x20 choices: [\"3.12\", \"3.13\"]\n\
+                 \x20 default: \"3.13\"\n\
+                 license:\n\
+                 \x20 choices:\n\
+                 \x20   MIT License: MIT\n\
+                 \x20   Apache License: Apache-2.0\n\
+                 port:\n\
+                 \x20 type: int\n\
+                 \x20 default: 8000\n\
+                 \x20 validator: \"{% if port < 1024 %}too low{% endif %}\"\n",
+            )],
+        );
+
+        let layout = load(&dir).unwrap().unwrap();
+        assert_eq!(layout.root, dir.join("template"));
+        assert_eq!(layout.exclude, ["*.bak"]);
+        assert_eq!(layout.answers_file.as_deref(), Some(".answers.yml"));
+        assert!(matches!(layout.mode, RenderMode::SuffixOnly));
+        assert_eq!(layout.namespace, None);
+
+        let names: Vec<&str> = layout.vars.iter().map(|var| var.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "project_name",
+                "use_docker",
+                "workers",
+                "python",
+                "license",
+                "port"
+            ]
+        );
+        let var = |name: &str| layout.vars.iter().find(|var| var.name == name).unwrap();
+        assert!(matches!(var("project_name").kind, VarKind::String));
+        assert_eq!(var("project_name").default, "demo");
+        assert!(matches!(var("use_docker").kind, VarKind::Bool));
+        assert!(matches!(var("workers").kind, VarKind::Int));
+        assert!(
+            matches!(&var("python").kind, VarKind::Choice(choices) if choices == &["3.12", "3.13"])
+        );
+        assert_eq!(var("python").help.as_deref(), Some("Python version"));
+        assert_eq!(var("python").default, "3.13");
+        assert!(
+            matches!(&var("license").kind, VarKind::Choice(choices) if choices == &["MIT", "Apache-2.0"])
+        );
+        assert!(matches!(var("port").kind, VarKind::Int));
+        assert_eq!(
+            var("port").validator.as_deref(),
+            Some("{% if port < 1024 %}too low{% endif %}")
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn defaults_without_settings() {
+        let dir = scratch_dir("copier-defaults");
+        assert!(load(&dir).unwrap().is_none());
+
+        write_files(&dir, &[("copier.yaml", "name: demo\n")]);
+        let layout = load(&dir).unwrap().unwrap();
+        assert_eq!(layout.root, dir);
+        assert_eq!(layout.answers_file.as_deref(), Some(DEFAULT_ANSWERS_FILE));
+        assert!(
+            layout
+                .exclude
+                .iter()
+                .any(|pattern| pattern == "copier.yaml")
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,471 @@
+pub mod cookiecutter;
+pub mod copier;
+pub mod repo;
+pub mod vars;
+
+#[cfg(test)]
+mod testing;
+
+use crate::utils::config_dir;
+use minijinja::value::ValueKind;
+use minijinja::{Environment, UndefinedBehavior, Value, escape_formatter};
+use std::{collections::BTreeMap, fs, io, path::Path, path::PathBuf, process::Command};
+use yaml_rust2::{Yaml, YamlEmitter};
+
+/// Built-in templates, keyed by tech and file name.
+///
+/// Any of these can be replaced by dropping a file with the same name into
+/// `~/.config/gardnr/templates/<tech>/`.
+const BUILTIN_TEMPLATES: &[(&str, &str, &str)] = &[
+    (
+        "django",
+        "base.py",
+        include_str!("../../templates/django/base.py"),
+    ),
+    (
+        "django",
+        "dev.py",
+        include_str!("../../templates/django/dev.py"),
+    ),
+    (
+        "django",
+        "prod.py",
+        include_str!("../../templates/django/prod.py"),
+    ),
+    (
+        "django",
+        "urls.py",
+        include_str!("../../templates/django/urls.py"),
+    ),
+    (
+        "django",
+        "app_urls.py",
+        include_str!("../../templates/django/app_urls.py"),
+    ),
+    (
+        "django",
+        "views.py",
+        include_str!("../../templates/django/views.py"),
+    ),
+    (
+        "django",
+        "serializers.py",
+        include_str!("../../templates/django/serializers.py"),
+    ),
+    ("django", "env", include_str!("../../templates/django/env")),
+    (
+        "flask",
+        "app.py",
+        include_str!("../../templates/flask/app.py"),
+    ),
+    (
+        "flask",
+        "factory.py",
+        include_str!("../../templates/flask/factory.py"),
+    ),
+    (
+        "flask",
+        "config.py",
+        include_str!("../../templates/flask/config.py"),
+    ),
+    (
+        "flask",
+        "api.py",
+        include_str!("../../templates/flask/api.py"),
+    ),
+    (
+        "flask",
+        "wsgi.py",
+        include_str!("../../templates/flask/wsgi.py"),
+    ),
+    (
+        "flask",
+        "conftest.py",
+        include_str!("../../templates/flask/conftest.py"),
+    ),
+    (
+        "flask",
+        "test_api.py",
+        include_str!("../../templates/flask/test_api.py"),
+    ),
+    (
+        "fastapi",
+        "main.py",
+        include_str!("../../templates/fastapi/main.py"),
+    ),
+    (
+        "fastapi",
+        "app_main.py",
+        include_str!("../../templates/fastapi/app_main.py"),
+    ),
+    (
+        "fastapi",
+        "config.py",
+        include_str!("../../templates/fastapi/config.py"),
+    ),
+    (
+        "fastapi",
+        "health.py",
+        include_str!("../../templates/fastapi/health.py"),
+    ),
+    (
+        "fastapi",
+        "hello.py",
+        include_str!("../../templates/fastapi/hello.py"),
+    ),
+    (
+        "fastapi",
+        "run.py",
+        include_str!("../../templates/fastapi/run.py"),
+    ),
+    (
+        "fastapi",
+        "test_api.py",
+        include_str!("../../templates/fastapi/test_api.py"),
+    ),
+    (
+        "fastapi",
+        "env",
+        include_str!("../../templates/fastapi/env"),
+    ),
+    (
+        "fastapi",
+        "db.py",
+        include_str!("../../templates/fastapi/db.py"),
+    ),
+    (
+        "fastapi",
+        "alembic.ini",
+        include_str!("../../templates/fastapi/alembic.ini"),
+    ),
+    (
+        "fastapi",
+        "alembic_env.py",
+        include_str!("../../templates/fastapi/alembic_env.py"),
+    ),
+    (
+        "fastapi",
+        "script.py.mako",
+        include_str!("../../templates/fastapi/script.py.mako"),
+    ),
+    (
+        "pyramid",
+        "app.py",
+        include_str!("../../templates/pyramid/app.py"),
+    ),
+    (
+        "litestar",
+        "app.py",
+        include_str!("../../templates/litestar/app.py"),
+    ),
+    (
+        "starlette",
+        "app.py",
+        include_str!("../../templates/starlette/app.py"),
+    ),
+    (
+        "sanic",
+        "app.py",
+        include_str!("../../templates/sanic/app.py"),
+    ),
+    (
+        "quart",
+        "app.py",
+        include_str!("../../templates/quart/app.py"),
+    ),
+    (
+        "streamlit",
+        "app.py",
+        include_str!("../../templates/streamlit/app.py"),
+    ),
+    (
+        "streamlit",
+        "config.toml",
+        include_str!("../../templates/streamlit/config.toml"),
+    ),
+    (
+        "gradio",
+        "app.py",
+        include_str!("../../templates/gradio/app.py"),
+    ),
+    (
+        "python",
+        "__init__.py",
+        include_str!("../../templates/python/__init__.py"),
+    ),
+    (
+        "python",
+        "__main__.py",
+        include_str!("../../templates/python/__main__.py"),
+    ),
+    (
+        "python",
+        "test_main.py",
+        include_str!("../../templates/python/test_main.py"),
+    ),
+    (
+        "python-cli",
+        "__init__.py",
+        include_str!("../../templates/python-cli/__init__.py"),
+    ),
+    (
+        "python-cli",
+        "__main__.py",
+        include_str!("../../templates/python-cli/__main__.py"),
+    ),
+    (
+        "python-cli",
+        "cli_typer.py",
+        include_str!("../../templates/python-cli/cli_typer.py"),
+    ),
+    (
+        "python-cli",
+        "cli_click.py",
+        include_str!("../../templates/python-cli/cli_click.py"),
+    ),
+    (
+        "python-cli",
+        "test_typer.py",
+        include_str!("../../templates/python-cli/test_typer.py"),
+    ),
+    (
+        "python-cli",
+        "test_click.py",
+        include_str!("../../templates/python-cli/test_click.py"),
+    ),
+    (
+        "ml",
+        "paths.py",
+        include_str!("../../templates/ml/paths.py"),
+    ),
+    (
+        "ml",
+        "exploration.ipynb",
+        include_str!("../../templates/ml/exploration.ipynb"),
+    ),
+    (
+        "express",
+        "server.js",
+        include_str!("../../templates/express/server.js"),
+    ),
+    (
+        "express",
+        "server.ts",
+        include_str!("../../templates/express/server.ts"),
+    ),
+    (
+        "express",
+        "errors.ts",
+        include_str!("../../templates/express/errors.ts"),
+    ),
+    (
+        "express",
+        "tsconfig.json",
+        include_str!("../../templates/express/tsconfig.json"),
+    ),
+];
+
+/// Variables available to a template while it is rendered.
+pub struct TemplateContext {
+    vars: BTreeMap<String, Value>,
+    versions: BTreeMap<String, String>,
+    /// Print booleans as `True`/`False`, as Python's Jinja2 does
+    python_bools: bool,
+}
+
+impl TemplateContext {
+    pub fn new(project: &str, component: &str) -> Self {
+        let mut context = TemplateContext {
+            vars: BTreeMap::new(),
+            versions: BTreeMap::new(),
+            python_bools: false,
+        };
+        context
+            .set("project", project)
+            .set("component", component)
+            .set("author", detect_author())
+            .set("version", "0.1.0");
+        context
+    }
+
+    pub fn set(&mut self, key: &str, value: impl Into<Value>) -> &mut Self {
+        self.vars.insert(key.to_string(), value.into());
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.vars.get(key)
+    }
+
+    /// Whether `key` is set to a truthy value.
+    pub fn is_enabled(&self, key: &str) -> bool {
+        self.get(key).is_some_and(|value| value.is_true())
+    }
+
+    /// Record the version of a tool or framework, exposed as `versions.<name>`.
+    pub fn set_version(&mut self, name: &str, version: &str) -> &mut Self {
+        self.versions.insert(name.to_string(), version.to_string());
+        self
+    }
+
+    /// Render booleans the way cookiecutter and copier templates expect,
+    /// e.g. `DEBUG = {{ cookiecutter.debug }}` as `DEBUG = True`.
+    pub fn use_python_bools(&mut self) -> &mut Self {
+        self.python_bools = true;
+        self
+    }
+
+    fn to_value(&self) -> Value {
+        let mut vars = self.vars.clone();
+        vars.insert(
+            "versions".to_string(),
+            Value::from_serialize(&self.versions),
+        );
+        Value::from_serialize(&vars)
+    }
+}
+
+/// Render a template string with the given context.
+pub fn render_str(source: &str, context: &TemplateContext) -> io::Result<String> {
+    render_with(source, context, UndefinedBehavior::Lenient)
+}
+
+/// Like [`render_str`], but fails on any variable the context does not define.
+pub fn render_str_strict(source: &str, context: &TemplateContext) -> io::Result<String> {
+    render_with(source, context, UndefinedBehavior::Strict)
+}
+
+fn render_with(
+    source: &str,
+    context: &TemplateContext,
+    undefined: UndefinedBehavior,
+) -> io::Result<String> {
+    let mut env = Environment::new();
+    env.set_keep_trailing_newline(true);
+    env.set_undefined_behavior(undefined);
+    // Python string methods and cookiecutter's default filters, so that
+    // `{{ cookiecutter.name.lower().replace(' ', '_') }}` works as it does there
+    env.set_unknown_method_callback(minijinja_contrib::pycompat::unknown_method_callback);
+    env.add_filter("slugify", slugify);
+    env.add_filter("jsonify", jsonify);
+    env.add_filter("to_nice_yaml", to_nice_yaml);
+    if context.python_bools {
+        env.set_formatter(|out, state, value| match value.kind() {
+            ValueKind::Bool if value.is_true() => out.write_str("True").map_err(Into::into),
+            ValueKind::Bool => out.write_str("False").map_err(Into::into),
+            _ => escape_formatter(out, state, value),
+        });
+    }
+    env.render_str(source, context.to_value())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+}
+
+/// Render the template `file` for `tech`, preferring a user override.
+pub fn render(tech: &str, file: &str, context: &TemplateContext) -> io::Result<String> {
+    let source = match user_override(tech, file) {
+        Some(path) => {
+            println!("→ Using template override {}", path.display());
+            fs::read_to_string(path)?
+        }
+        None => builtin(tech, file)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no template '{}' for {}", file, tech),
+                )
+            })?
+            .to_string(),
+    };
+
+    render_str(&source, context)
+}
+
+/// Render the template `file` for `tech` into `dest`.
+pub fn write(tech: &str, file: &str, dest: &Path, context: &TemplateContext) -> io::Result<()> {
+    let content = render(tech, file, context)?;
+    fs::write(dest, content)
+}
+
+fn slugify(value: String) -> String {
+    let mut slug = String::new();
+    for c in value.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+fn jsonify(value: Value) -> Result<String, minijinja::Error> {
+    serde_json::to_string(&value)
+        .map_err(|e| minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, e.to_string()))
+}
+
+/// Ansible's `to_nice_yaml`, which copier templates use to write their
+/// answers file.
+fn to_nice_yaml(value: Value) -> Result<String, minijinja::Error> {
+    let json = serde_json::to_value(&value).map_err(|e| {
+        minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, e.to_string())
+    })?;
+    let mut yaml = String::new();
+    YamlEmitter::new(&mut yaml)
+        .dump(&json_to_yaml(json))
+        .map_err(|e| {
+            minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, e.to_string())
+        })?;
+    // The emitter starts a document; the filter output is a fragment
+    let yaml = yaml
+        .strip_prefix("---")
+        .unwrap_or(&yaml)
+        .trim_start_matches([' ', '\n']);
+    Ok(format!("{}\n", yaml))
+}
+
+fn json_to_yaml(json: serde_json::Value) -> Yaml {
+    match json {
+        serde_json::Value::Null => Yaml::Null,
+        serde_json::Value::Bool(b) => Yaml::Boolean(b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Yaml::Integer(i),
+            None => Yaml::Real(n.to_string()),
+        },
+        serde_json::Value::String(s) => Yaml::String(s),
+        serde_json::Value::Array(items) => {
+            Yaml::Array(items.into_iter().map(json_to_yaml).collect())
+        }
+        serde_json::Value::Object(fields) => Yaml::Hash(
+            fields
+                .into_iter()
+                .map(|(key, value)| (Yaml::String(key), json_to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn builtin(tech: &str, file: &str) -> Option<&'static str> {
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(t, f, _)| *t == tech && *f == file)
+        .map(|(_, _, content)| *content)
+}
+
+fn user_override(tech: &str, file: &str) -> Option<PathBuf> {
+    let path = config_dir()?.join("templates").join(tech).join(file);
+    path.is_file().then_some(path)
+}
+
+fn detect_author() -> String {
+    let git_name = Command::new("git")
+        .args(["config", "--get", "user.name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|name| !name.is_empty());
+
+    git_name
+        .or_else(|| std::env::var("USER").ok())
+        .unwrap_or_default()
+}
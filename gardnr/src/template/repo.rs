@@ -0,0 +1,673 @@
+use super::vars::{self, TemplateVar};
+use super::{TemplateContext, cookiecutter, copier, render_str, render_str_strict};
+use crate::git;
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Suffix that forces a file to be rendered; it is stripped from the output name.
+const TEMPLATE_SUFFIX: &str = ".jinja";
+
+/// Where a template repository comes from, as given after `template:`.
+pub enum TemplateSource {
+    /// `git+<url>[#<revision>]`
+    Git {
+        url: String,
+        revision: Option<String>,
+    },
+    /// A plain directory on disk
+    Local(PathBuf),
+}
+
+/// The template a component was scaffolded from, for the project manifest.
+pub struct ScaffoldedTemplate {
+    pub source: String,
+    pub revision: Option<String>,
+}
+
+/// A template fetched to disk; temporary clones are removed on drop.
+pub struct TemplateCheckout {
+    pub path: PathBuf,
+    pub revision: Option<String>,
+    temporary: bool,
+}
+
+/// Which files of a template have their contents rendered.
+pub enum RenderMode {
+    /// `.jinja` files, plus any other text file whose placeholders all resolve
+    Auto,
+    /// Every text file (cookiecutter)
+    All,
+    /// Only `.jinja` files (copier)
+    SuffixOnly,
+}
+
+/// How to turn a checked-out template into a component.
+pub struct TemplateLayout {
+    /// Directory whose contents become the component directory
+    pub root: PathBuf,
+    pub vars: Vec<TemplateVar>,
+    /// Name the variables are exposed under, e.g. `cookiecutter`
+    pub namespace: Option<&'static str>,
+    pub mode: RenderMode,
+    /// Globs (relative to `root`) copied without rendering their contents
+    pub copy_without_render: Vec<String>,
+    /// Globs (relative to `root`) left out entirely
+    pub exclude: Vec<String>,
+    /// Where copier templates record their answers, exposed as
+    /// `_copier_conf.answers_file`
+    pub answers_file: Option<String>,
+    /// Whether booleans print as `True`/`False` (cookiecutter and copier run
+    /// Python's Jinja2)
+    pub python_bools: bool,
+}
+
+impl TemplateSource {
+    pub fn parse(spec: &str) -> TemplateSource {
+        match spec.strip_prefix("git+") {
+            Some(rest) => {
+                let (url, revision) = match rest.split_once('#') {
+                    Some((url, revision)) if !revision.is_empty() => {
+                        (url, Some(revision.to_string()))
+                    }
+                    Some((url, _)) => (url, None),
+                    None => (rest, None),
+                };
+                TemplateSource::Git {
+                    url: url.to_string(),
+                    revision,
+                }
+            }
+            None => TemplateSource::Local(expand_home(spec)),
+        }
+    }
+}
+
+impl TemplateCheckout {
+    /// Clone or locate the template at `spec`; `label` keeps clones apart.
+    pub fn fetch(spec: &str, label: &str) -> io::Result<TemplateCheckout> {
+        match TemplateSource::parse(spec) {
+            TemplateSource::Git { url, revision } => {
+                let path = std::env::temp_dir().join(format!(
+                    "gardnr-template-{}-{}",
+                    std::process::id(),
+                    label
+                ));
+                if path.exists() {
+                    fs::remove_dir_all(&path)?;
+                }
+
+                // Constructed before cloning so a failed clone is still cleaned up
+                let mut checkout = TemplateCheckout {
+                    path,
+                    revision: None,
+                    temporary: true,
+                };
+                git::clone(&url, revision.as_deref(), &checkout.path)?;
+                checkout.revision = git::head_revision(&checkout.path);
+
+                Ok(checkout)
+            }
+            TemplateSource::Local(path) => {
+                if !path.is_dir() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("template directory {} not found", path.display()),
+                    ));
+                }
+                let revision = if path.join(".git").exists() {
+                    git::head_revision(&path)
+                } else {
+                    None
+                };
+
+                Ok(TemplateCheckout {
+                    path,
+                    revision,
+                    temporary: false,
+                })
+            }
+        }
+    }
+
+    /// Work out how this checkout is laid out (cookiecutter, copier or plain).
+    pub fn layout(&self) -> io::Result<TemplateLayout> {
+        if let Some(layout) = cookiecutter::load(&self.path)? {
+            println!("→ Detected cookiecutter template");
+            return Ok(layout);
+        }
+        if let Some(layout) = copier::load(&self.path)? {
+            println!("→ Detected copier template");
+            return Ok(layout);
+        }
+
+        Ok(TemplateLayout {
+            root: self.path.clone(),
+            vars: vars::load_declarations(&self.path)?,
+            namespace: None,
+            mode: RenderMode::Auto,
+            copy_without_render: Vec::new(),
+            exclude: vec![vars::DECLARATIONS_FILE.to_string()],
+            answers_file: None,
+            python_bools: false,
+        })
+    }
+}
+
+impl Drop for TemplateCheckout {
+    fn drop(&mut self) {
+        if self.temporary {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+}
+
+/// Ask the user for the variables the template at `spec` declares, except
+/// for those already given in `answers`.
+pub fn template_answers(
+    spec: &str,
+    context: &mut TemplateContext,
+    answers: &BTreeMap<String, String>,
+) -> io::Result<BTreeMap<String, String>> {
+    let checkout = TemplateCheckout::fetch(spec, "questions")?;
+    let layout = checkout.layout()?;
+
+    vars::resolve(&layout.vars, layout.namespace, answers, true, context)
+}
+
+/// Copy or clone the template at `spec` into `component_dir`, rendering its
+/// file contents and path names with `context` and the given `answers`.
+/// Variables without an answer take their default.
+pub fn scaffold_from_template(
+    spec: &str,
+    component_dir: &Path,
+    context: &mut TemplateContext,
+    answers: &BTreeMap<String, String>,
+) -> io::Result<ScaffoldedTemplate> {
+    println!("→ Scaffolding from template {}", spec);
+
+    let label = component_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let checkout = TemplateCheckout::fetch(spec, &label)?;
+    let layout = checkout.layout()?;
+
+    if layout.python_bools {
+        context.use_python_bools();
+    }
+    vars::resolve(&layout.vars, layout.namespace, answers, false, context)?;
+    if let Some(answers_file) = &layout.answers_file {
+        let src_path = match TemplateSource::parse(spec) {
+            TemplateSource::Git { url, .. } => url,
+            TemplateSource::Local(path) => path.display().to_string(),
+        };
+        copier::publish_answers(
+            context,
+            &layout.vars,
+            answers_file,
+            &src_path,
+            checkout.revision.as_deref(),
+        );
+    }
+    render_tree(&layout.root, component_dir, Path::new(""), &layout, context)?;
+
+    println!("→ Template rendered into {}", component_dir.display());
+    if let Some(revision) = &checkout.revision {
+        println!("   Revision: {}", revision);
+    }
+
+    Ok(ScaffoldedTemplate {
+        source: spec.to_string(),
+        revision: checkout.revision.clone(),
+    })
+}
+
+/// Render every file below `src` into `dest`, skipping git metadata.
+///
+/// Path names are always rendered. Files ending in `.jinja` are rendered and
+/// lose the suffix; what happens to other text files depends on the layout's
+/// [`RenderMode`]. In `Auto` mode they are copied verbatim unless every
+/// placeholder resolves, so that files using `{{ }}` for their own purposes
+/// (Vue, Handlebars, ...) survive untouched.
+fn render_tree(
+    src: &Path,
+    dest: &Path,
+    relative: &Path,
+    layout: &TemplateLayout,
+    context: &TemplateContext,
+) -> io::Result<()> {
+    fs::create_dir_all(dest)?;
+
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let relative = relative.join(&file_name);
+        if file_name == ".git" || matches_any(&layout.exclude, &relative) {
+            continue;
+        }
+
+        let mut target_name = render_str(&file_name, context)?;
+        let has_suffix = target_name.ends_with(TEMPLATE_SUFFIX);
+        if has_suffix {
+            target_name.truncate(target_name.len() - TEMPLATE_SUFFIX.len());
+        }
+        // Names like `{% if docker %}Dockerfile{% endif %}` leave out the entry
+        if target_name.is_empty() {
+            continue;
+        }
+        let target = dest.join(target_name);
+
+        if entry.file_type()?.is_dir() {
+            render_tree(&entry.path(), &target, &relative, layout, context)?;
+            continue;
+        }
+
+        let bytes = fs::read(entry.path())?;
+        let verbatim = matches_any(&layout.copy_without_render, &relative);
+        match String::from_utf8(bytes) {
+            Ok(text) if verbatim => fs::write(&target, text)?,
+            Ok(text) if has_suffix => {
+                fs::write(&target, render_str_strict(&text, context)?)?;
+            }
+            Ok(text) if !text.contains("{{") && !text.contains("{%") => fs::write(&target, text)?,
+            Ok(text) => match layout.mode {
+                RenderMode::SuffixOnly => fs::write(&target, text)?,
+                RenderMode::All => match render_str(&text, context) {
+                    Ok(rendered) => fs::write(&target, rendered)?,
+                    Err(e) => {
+                        eprintln!(
+                            "⚠️  Could not render {}, copied as-is: {}",
+                            relative.display(),
+                            e
+                        );
+                        fs::write(&target, text)?
+                    }
+                },
+                RenderMode::Auto => match render_str_strict(&text, context) {
+                    Ok(rendered) => fs::write(&target, rendered)?,
+                    Err(_) => fs::write(&target, text)?,
+                },
+            },
+            Err(e) => fs::write(&target, e.into_bytes())?,
+        }
+        fs::set_permissions(&target, entry.metadata()?.permissions())?;
+    }
+
+    Ok(())
+}
+
+fn matches_any(patterns: &[String], path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    patterns.iter().any(|pattern| {
+        let pattern = pattern.trim_end_matches('/');
+        glob_match(pattern.as_bytes(), path.as_bytes())
+            || path
+                .rsplit('/')
+                .next()
+                .is_some_and(|name| glob_match(pattern.as_bytes(), name.as_bytes()))
+    })
+}
+
+/// fnmatch-style matching: `*` matches any run of characters, `?` any one,
+/// `[abc]` or `[a-z]` any listed character and `[!abc]` any other.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            glob_match(&pattern[1..], text) || (!text.is_empty() && glob_match(pattern, &text[1..]))
+        }
+        (Some(b'?'), Some(_)) => glob_match(&pattern[1..], &text[1..]),
+        (Some(b'['), Some(c)) => {
+            // As in fnmatch, a `]` right after `[` or `[!` is a member
+            let start = if pattern.get(1) == Some(&b'!') { 3 } else { 2 };
+            match pattern
+                .iter()
+                .skip(start)
+                .position(|&b| b == b']')
+                .map(|end| end + start)
+            {
+                Some(end) => {
+                    class_match(&pattern[1..end], *c) && glob_match(&pattern[end + 1..], &text[1..])
+                }
+                None => *c == b'[' && glob_match(&pattern[1..], &text[1..]),
+            }
+        }
+        (Some(p), Some(c)) => p == c && glob_match(&pattern[1..], &text[1..]),
+        _ => false,
+    }
+}
+
+/// Whether `c` is in the bracket expression `class` (without the brackets).
+fn class_match(class: &[u8], c: u8) -> bool {
+    let (negated, mut class) = match class.split_first() {
+        Some((b'!', rest)) => (true, rest),
+        _ => (false, class),
+    };
+    let mut found = false;
+    loop {
+        match class {
+            [low, b'-', high, rest @ ..] => {
+                found |= (*low..=*high).contains(&c);
+                class = rest;
+            }
+            [member, rest @ ..] => {
+                found |= *member == c;
+                class = rest;
+            }
+            [] => return found != negated,
+        }
+    }
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::{entries, git, scratch_dir, write_files};
+    use super::*;
+
+    #[test]
+    fn copier_template_writes_its_answers_file() {
+        let dir = scratch_dir("repo-copier-answers");
+        let template = dir.join("template");
+        write_files(
+            &template,
+            &[
+                (
+                    "copier.yml",
+                    "project_name: demo\nuse_docker:\n  type: bool\n  default: false\n",
+                ),
+                (
+                    "{{_copier_conf.answers_file}}.jinja",
+                    "# Changes here will be overwritten by Copier\n{{ _copier_answers|to_nice_yaml -}}\n",
+                ),
+                (
+                    "{% if use_docker %}Dockerfile{% endif %}.jinja",
+                    "FROM python\n",
+                ),
+                ("README.md.jinja", "# {{ project_name }}\n"),
+            ],
+        );
+        let component_dir = dir.join("web");
+        let mut context = TemplateContext::new("shop", "web");
+        let answers = BTreeMap::from([("project_name".to_string(), "shop".to_string())]);
+
+        scaffold_from_template(
+            template.to_str().unwrap(),
+            &component_dir,
+            &mut context,
+            &answers,
+        )
+        .unwrap();
+
+        let answers_file = fs::read_to_string(component_dir.join(".copier-answers.yml")).unwrap();
+        assert_eq!(
+            answers_file,
+            format!(
+                "# Changes here will be overwritten by Copier\n\
+                 _src_path: {}\nproject_name: shop\nuse_docker: false\n",
+                template.display()
+            )
+        );
+        assert_eq!(
+            fs::read_to_string(component_dir.join("README.md")).unwrap(),
+            "# shop\n"
+        );
+        assert_eq!(
+            entries(&component_dir),
+            [".copier-answers.yml", "README.md"]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parses_git_and_local_sources() {
+        match TemplateSource::parse("git+file:///srv/api.git#v2") {
+            TemplateSource::Git { url, revision } => {
+                assert_eq!(url, "file:///srv/api.git");
+                assert_eq!(revision.as_deref(), Some("v2"));
+            }
+            TemplateSource::Local(_) => panic!("expected a git source"),
+        }
+        match TemplateSource::parse("git+https://example.com/api.git#") {
+            TemplateSource::Git { url, revision } => {
+                assert_eq!(url, "https://example.com/api.git");
+                assert_eq!(revision, None);
+            }
+            TemplateSource::Local(_) => panic!("expected a git source"),
+        }
+        match TemplateSource::parse("templates/api") {
+            TemplateSource::Local(path) => assert_eq!(path, PathBuf::from("templates/api")),
+            TemplateSource::Git { .. } => panic!("expected a local source"),
+        }
+        if let Some(home) = std::env::var_os("HOME") {
+            match TemplateSource::parse("~/templates/api") {
+                TemplateSource::Local(path) => {
+                    assert_eq!(path, PathBuf::from(home).join("templates/api"))
+                }
+                TemplateSource::Git { .. } => panic!("expected a local source"),
+            }
+        }
+    }
+
+    #[test]
+    fn missing_local_template_is_not_found() {
+        let dir = scratch_dir("repo-missing");
+        let error = TemplateCheckout::fetch(dir.join("nope").to_str().unwrap(), "missing")
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn scaffolds_revision_of_git_template() {
+        let dir = scratch_dir("repo-git");
+        let work = dir.join("work");
+        fs::create_dir_all(&work).unwrap();
+        git(&work, &["init", "--quiet"]);
+        write_files(&work, &[("README.md", "# {{ component }} v1\n")]);
+        git(&work, &["add", "-A"]);
+        git(&work, &["commit", "--quiet", "-m", "v1"]);
+        git(&work, &["tag", "v1"]);
+        let v1 = git(&work, &["rev-parse", "HEAD"]);
+        write_files(&work, &[("README.md", "# {{ component }} v2\n")]);
+        git(&work, &["commit", "--quiet", "-am", "v2"]);
+        git(
+            &dir,
+            &["clone", "--quiet", "--bare", "work", "template.git"],
+        );
+
+        let spec = format!("git+file://{}#v1", dir.join("template.git").display());
+        let component_dir = dir.join("git-api");
+        let mut context = TemplateContext::new("shop", "git-api");
+        let scaffolded =
+            scaffold_from_template(&spec, &component_dir, &mut context, &BTreeMap::new()).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(component_dir.join("README.md")).unwrap(),
+            "# git-api v1\n"
+        );
+        assert_eq!(scaffolded.revision, Some(v1));
+        assert_eq!(entries(&component_dir), ["README.md"]);
+        // The clone is temporary
+        assert!(
+            !std::env::temp_dir()
+                .join(format!("gardnr-template-{}-git-api", std::process::id()))
+                .exists()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn plain_template_renders_what_resolves() {
+        let dir = scratch_dir("repo-plain");
+        let template = dir.join("template");
+        write_files(
+            &template,
+            &[
+                (
+                    vars::DECLARATIONS_FILE,
+                    "[[var]]\nname = \"port\"\ntype = \"int\"\ndefault = 8000\n",
+                ),
+                ("app.py", "PORT = {{ port }}\n"),
+                // Vue's own placeholders do not resolve, so the file is kept as-is
+                ("App.vue", "<p>{{ message }}</p>\n"),
+                ("settings.toml.jinja", "name = \"{{ component }}\"\n"),
+                ("{{ component }}_pkg/__init__.py", ""),
+            ],
+        );
+        let component_dir = dir.join("plain-api");
+        let mut context = TemplateContext::new("shop", "plain-api");
+        let answers = BTreeMap::from([("port".to_string(), "9000".to_string())]);
+
+        scaffold_from_template(
+            template.to_str().unwrap(),
+            &component_dir,
+            &mut context,
+            &answers,
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries(&component_dir),
+            ["App.vue", "app.py", "plain-api_pkg", "settings.toml"]
+        );
+        let read = |name: &str| fs::read_to_string(component_dir.join(name)).unwrap();
+        assert_eq!(read("app.py"), "PORT = 9000\n");
+        assert_eq!(read("App.vue"), "<p>{{ message }}</p>\n");
+        assert_eq!(read("settings.toml"), "name = \"plain-api\"\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn glob_matches_like_fnmatch() {
+        let matches = |pattern: &str, text: &str| glob_match(pattern.as_bytes(), text.as_bytes());
+        assert!(matches("*.py[co]", "app.pyc"));
+        assert!(!matches("*.py[co]", "app.py"));
+        assert!(matches("file?.txt", "file1.txt"));
+        assert!(!matches("file?.txt", "file.txt"));
+        assert!(matches("[a-c]*", "build"));
+        assert!(!matches("[a-c]*", "dist"));
+        assert!(matches("*.[!j]*", "logo.png"));
+        assert!(!matches("*.[!j]*", "index.js"));
+        assert!(matches("[!]]", "a"));
+        assert!(!matches("[!]]", "]"));
+        assert!(matches("[]]", "]"));
+        assert!(matches("[x", "[x"));
+    }
+
+    #[test]
+    fn patterns_match_paths_and_file_names() {
+        let patterns = vec![
+            "static/*".to_string(),
+            "*.png".to_string(),
+            "build/".to_string(),
+        ];
+        assert!(matches_any(&patterns, Path::new("static/app.js")));
+        assert!(matches_any(&patterns, Path::new("assets/img/logo.png")));
+        assert!(matches_any(&patterns, Path::new("build")));
+        assert!(!matches_any(&patterns, Path::new("src/app.js")));
+    }
+
+    fn render_with_mode(name: &str, mode: RenderMode, copy_without_render: &[&str]) -> PathBuf {
+        let dir = scratch_dir(name);
+        let src = dir.join("template");
+        write_files(
+            &src,
+            &[
+                ("config.py", "NAME = \"{{ component }}\"\n"),
+                ("broken.txt", "{{ unclosed\n"),
+                ("page.html", "<h1>{{ component }}</h1>\n"),
+                ("ignored.log", "{{ component }}\n"),
+            ],
+        );
+        let layout = TemplateLayout {
+            root: src.clone(),
+            vars: Vec::new(),
+            namespace: None,
+            mode,
+            copy_without_render: copy_without_render.iter().map(|s| s.to_string()).collect(),
+            exclude: vec!["*.log".to_string()],
+            answers_file: None,
+            python_bools: false,
+        };
+        let dest = dir.join("out");
+        let context = TemplateContext::new("shop", "api");
+        render_tree(&src, &dest, Path::new(""), &layout, &context).unwrap();
+        dest
+    }
+
+    #[test]
+    fn all_mode_renders_every_text_file() {
+        let dest = render_with_mode("repo-mode-all", RenderMode::All, &["*.htm[!x]"]);
+        let read = |name: &str| fs::read_to_string(dest.join(name)).unwrap();
+        assert_eq!(entries(&dest), ["broken.txt", "config.py", "page.html"]);
+        assert_eq!(read("config.py"), "NAME = \"api\"\n");
+        // Left alone by `_copy_without_render`, copied as-is when it cannot render
+        assert_eq!(read("page.html"), "<h1>{{ component }}</h1>\n");
+        assert_eq!(read("broken.txt"), "{{ unclosed\n");
+
+        fs::remove_dir_all(dest.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn suffix_only_mode_copies_other_files() {
+        let dest = render_with_mode("repo-mode-suffix", RenderMode::SuffixOnly, &[]);
+        let read = |name: &str| fs::read_to_string(dest.join(name)).unwrap();
+        assert_eq!(read("config.py"), "NAME = \"{{ component }}\"\n");
+        assert_eq!(read("page.html"), "<h1>{{ component }}</h1>\n");
+
+        fs::remove_dir_all(dest.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn cookiecutter_booleans_render_like_python() {
+        let dir = scratch_dir("repo-cookiecutter-bools");
+        let template = dir.join("template");
+        write_files(
+            &template,
+            &[
+                (
+                    "cookiecutter.json",
+                    r#"{"project_slug": "demo", "debug": true, "use_docker": false}"#,
+                ),
+                (
+                    "{{cookiecutter.project_slug}}/settings.py",
+                    "DEBUG = {{ cookiecutter.debug }}\n\
+                     DOCKER = {{ cookiecutter.use_docker }}\n\
+                     {% if cookiecutter.use_docker %}IMAGE = \"app\"\n{% endif %}",
+                ),
+            ],
+        );
+        let component_dir = dir.join("cc-api");
+        let mut context = TemplateContext::new("shop", "cc-api");
+
+        scaffold_from_template(
+            template.to_str().unwrap(),
+            &component_dir,
+            &mut context,
+            &BTreeMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(component_dir.join("settings.py")).unwrap(),
+            "DEBUG = True\nDOCKER = False\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
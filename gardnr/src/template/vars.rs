@@ -0,0 +1,506 @@
+use super::{TemplateContext, render_str};
+use minijinja::Value;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+/// File in which a plain template repository declares its variables.
+pub const DECLARATIONS_FILE: &str = "gardnr-template.toml";
+
+/// What kind of answer a template variable expects.
+pub enum VarKind {
+    String,
+    Bool,
+    Int,
+    /// Comma-separated values, e.g. `https://a.example,https://b.example`
+    List,
+    Choice(Vec<String>),
+}
+
+/// A variable declared by a template or tech (e.g. an entry in `cookiecutter.json`).
+pub struct TemplateVar {
+    pub name: String,
+    pub kind: VarKind,
+    /// Default value; may itself be a template referring to earlier variables
+    pub default: String,
+    pub help: Option<String>,
+    /// Whether the user is asked for this variable at all
+    pub prompt: bool,
+    /// Template rendered with the candidate value in scope; any output is
+    /// taken as the error message (same convention as copier)
+    pub validator: Option<String>,
+}
+
+impl TemplateVar {
+    pub fn new(name: &str, kind: VarKind, default: &str) -> Self {
+        TemplateVar {
+            name: name.to_string(),
+            kind,
+            default: default.to_string(),
+            help: None,
+            prompt: true,
+            validator: None,
+        }
+    }
+
+    pub fn help(mut self, help: &str) -> Self {
+        self.help = Some(help.to_string());
+        self
+    }
+
+    pub fn validator(mut self, validator: &str) -> Self {
+        self.validator = Some(validator.to_string());
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.prompt = false;
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct Declarations {
+    #[serde(default)]
+    var: Vec<Declaration>,
+}
+
+#[derive(Deserialize)]
+struct Declaration {
+    name: String,
+    #[serde(default, rename = "type")]
+    kind: Option<String>,
+    default: Option<toml::Value>,
+    help: Option<String>,
+    choices: Option<Vec<String>>,
+    validator: Option<String>,
+}
+
+/// Read the `[[var]]` declarations of a plain template, if it has any.
+pub fn load_declarations(dir: &Path) -> io::Result<Vec<TemplateVar>> {
+    let path = dir.join(DECLARATIONS_FILE);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(path)?;
+    let declarations: Declarations = toml::from_str(&content)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+    declarations
+        .var
+        .into_iter()
+        .map(|declaration| {
+            let kind = match (declaration.kind.as_deref(), declaration.choices) {
+                (_, Some(choices)) => VarKind::Choice(choices),
+                (None | Some("string" | "str"), None) => VarKind::String,
+                (Some("bool"), None) => VarKind::Bool,
+                (Some("int"), None) => VarKind::Int,
+                (Some("list"), None) => VarKind::List,
+                (Some(other), None) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "variable '{}' has unknown type '{}'",
+                            declaration.name, other
+                        ),
+                    ));
+                }
+            };
+            let default = match declaration.default {
+                Some(toml::Value::String(s)) => s,
+                Some(other) => other.to_string(),
+                None => String::new(),
+            };
+
+            Ok(TemplateVar {
+                name: declaration.name,
+                kind,
+                default,
+                help: declaration.help,
+                prompt: true,
+                validator: declaration.validator,
+            })
+        })
+        .collect()
+}
+
+/// Resolve `vars` in declaration order and publish them on `context`.
+///
+/// Values come from `answers` first, then from the user when `interactive`
+/// is set, and fall back to the rendered default. With a `namespace` the
+/// variables are exposed as `<namespace>.<name>` (cookiecutter style),
+/// otherwise at the top level (copier style). Every value is checked against
+/// its type and validator; the user is asked again on a bad answer, anything
+/// else is an error. Answers to variables `vars` does not declare are
+/// reported and left out.
+pub fn resolve(
+    vars: &[TemplateVar],
+    namespace: Option<&str>,
+    answers: &BTreeMap<String, String>,
+    interactive: bool,
+    context: &mut TemplateContext,
+) -> io::Result<BTreeMap<String, String>> {
+    let component = context
+        .get("component")
+        .and_then(|component| component.as_str())
+        .unwrap_or_default()
+        .to_string();
+    warn_undeclared(vars, answers, &component);
+
+    let mut resolved = BTreeMap::new();
+    let mut scope: BTreeMap<String, Value> = BTreeMap::new();
+
+    for var in vars {
+        let choices = match &var.kind {
+            VarKind::Choice(choices) => choices
+                .iter()
+                .map(|choice| render_str(choice, context))
+                .collect::<io::Result<Vec<_>>>()?,
+            _ => Vec::new(),
+        };
+        let default = match &var.kind {
+            VarKind::Choice(_) if var.default.is_empty() => {
+                choices.first().cloned().unwrap_or_default()
+            }
+            _ => render_str(&var.default, context)?,
+        };
+
+        let mut given = answers.get(&var.name).cloned();
+        let (raw, value) = loop {
+            let (raw, from_user) = match given.take() {
+                Some(answer) => (answer, false),
+                None if interactive && var.prompt => match ask(var, &default, &choices) {
+                    Some(answer) => (answer, true),
+                    None => (default.clone(), false),
+                },
+                None => (default.clone(), false),
+            };
+
+            let checked = check(var, &raw, &choices).and_then(|value| {
+                publish(context, namespace, &mut scope, &var.name, value.clone());
+                validate(var, context).map(|_| value)
+            });
+            match checked {
+                Ok(value) => break (raw, value),
+                Err(message) if from_user => eprintln!("✖ {}", message),
+                Err(message) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid value '{}' for {}: {}", raw, var.name, message),
+                    ));
+                }
+            }
+        };
+
+        publish(context, namespace, &mut scope, &var.name, value);
+        resolved.insert(var.name.clone(), raw);
+    }
+
+    Ok(resolved)
+}
+
+/// Warn about each answer that matches none of `vars`, e.g. a mistyped
+/// `--var prot=8080`.
+pub fn warn_undeclared(vars: &[TemplateVar], answers: &BTreeMap<String, String>, component: &str) {
+    for key in answers.keys() {
+        if !vars.iter().any(|var| &var.name == key) {
+            eprintln!("⚠️  {} has no variable '{}', ignoring it", component, key);
+        }
+    }
+}
+
+fn publish(
+    context: &mut TemplateContext,
+    namespace: Option<&str>,
+    scope: &mut BTreeMap<String, Value>,
+    name: &str,
+    value: Value,
+) {
+    match namespace {
+        Some(namespace) => {
+            scope.insert(name.to_string(), value);
+            context.set(namespace, Value::from_serialize(&*scope));
+        }
+        None => {
+            context.set(name, value);
+        }
+    }
+}
+
+/// Convert `raw` to the variable's type.
+fn check(var: &TemplateVar, raw: &str, choices: &[String]) -> Result<Value, String> {
+    match var.kind {
+        VarKind::Bool => match raw.trim().to_lowercase().as_str() {
+            "y" | "yes" | "true" | "1" | "on" => Ok(Value::from(true)),
+            "n" | "no" | "false" | "0" | "off" => Ok(Value::from(false)),
+            _ => Err("expected yes or no".to_string()),
+        },
+        VarKind::Int => raw
+            .trim()
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| "expected a whole number".to_string()),
+        VarKind::List => Ok(Value::from(
+            raw.split(',')
+                .map(|item| item.trim())
+                .filter(|item| !item.is_empty())
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>(),
+        )),
+        VarKind::Choice(_) if !choices.iter().any(|choice| choice == raw) => {
+            Err(format!("expected one of {}", choices.join(", ")))
+        }
+        _ => Ok(Value::from(raw)),
+    }
+}
+
+fn validate(var: &TemplateVar, context: &TemplateContext) -> Result<(), String> {
+    let Some(validator) = &var.validator else {
+        return Ok(());
+    };
+
+    match render_str(validator, context) {
+        Ok(message) if message.trim().is_empty() => Ok(()),
+        Ok(message) => Err(message.trim().to_string()),
+        Err(e) => Err(format!("validator failed: {}", e)),
+    }
+}
+
+/// Ask for a value on stdin; `None` if nothing could be read.
+fn ask(var: &TemplateVar, default: &str, choices: &[String]) -> Option<String> {
+    let question = var.help.as_deref().unwrap_or(&var.name);
+
+    if !choices.is_empty() {
+        println!("{}:", question);
+        for (i, choice) in choices.iter().enumerate() {
+            println!("  {}) {}", i + 1, choice);
+        }
+        print!("Choose from 1-{} [{}]: ", choices.len(), default);
+    } else if let VarKind::Bool = var.kind {
+        let hint = if parse_bool(default) { "Y/n" } else { "y/N" };
+        print!("{} ({}): ", question, hint);
+    } else {
+        print!("{} [{}]: ", question, default);
+    }
+    let _ = io::stdout().flush();
+
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(0) | Err(_) => return None,
+        Ok(_) => {}
+    }
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Some(default.to_string());
+    }
+    match input.parse::<usize>() {
+        Ok(n) if n >= 1 && n <= choices.len() => Some(choices[n - 1].clone()),
+        _ => Some(input.to_string()),
+    }
+}
+
+fn parse_bool(value: &str) -> bool {
+    matches!(
+        value.trim().to_lowercase().as_str(),
+        "y" | "yes" | "true" | "1" | "on"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::{scratch_dir, write_files};
+    use super::*;
+
+    fn answers(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn loads_declarations() {
+        let dir = scratch_dir("vars-declarations");
+        write_files(
+            &dir,
+            &[(
+                DECLARATIONS_FILE,
+                "[[var]]\nname = \"name\"\n\n\
+                 [[var]]\nname = \"port\"\ntype = \"int\"\ndefault = 8000\n\
+                 validator = \"{% if port < 1024 %}too low{% endif %}\"\n\n\
+                 [[var]]\nname = \"docker\"\ntype = \"bool\"\ndefault = true\nhelp = \"Docker?\"\n\n\
+                 [[var]]\nname = \"origins\"\ntype = \"list\"\n\n\
+                 [[var]]\nname = \"db\"\nchoices = [\"sqlite\", \"postgres\"]\n",
+            )],
+        );
+
+        let vars = load_declarations(&dir).unwrap();
+        assert!(matches!(vars[0].kind, VarKind::String));
+        assert_eq!(vars[0].default, "");
+        assert!(matches!(vars[1].kind, VarKind::Int));
+        assert_eq!(vars[1].default, "8000");
+        assert!(vars[1].validator.is_some());
+        assert!(matches!(vars[2].kind, VarKind::Bool));
+        assert_eq!(vars[2].default, "true");
+        assert_eq!(vars[2].help.as_deref(), Some("Docker?"));
+        assert!(matches!(vars[3].kind, VarKind::List));
+        assert!(matches!(&vars[4].kind, VarKind::Choice(choices) if choices.len() == 2));
+
+        write_files(
+            &dir,
+            &[(
+                DECLARATIONS_FILE,
+                "[[var]]\nname = \"x\"\ntype = \"float\"\n",
+            )],
+        );
+        let error = load_declarations(&dir).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("unknown type 'float'"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn converts_answers_to_their_type() {
+        let var = |kind| TemplateVar::new("x", kind, "");
+        for yes in ["y", "Yes", "TRUE", "1", "on"] {
+            assert_eq!(check(&var(VarKind::Bool), yes, &[]), Ok(Value::from(true)));
+        }
+        for no in ["n", "No", "false", "0", "off"] {
+            assert_eq!(check(&var(VarKind::Bool), no, &[]), Ok(Value::from(false)));
+        }
+        assert!(check(&var(VarKind::Bool), "maybe", &[]).is_err());
+        assert_eq!(check(&var(VarKind::Int), " 42 ", &[]), Ok(Value::from(42)));
+        assert!(check(&var(VarKind::Int), "4.2", &[]).is_err());
+        assert_eq!(
+            check(
+                &var(VarKind::List),
+                "https://a.example, ,https://b.example",
+                &[]
+            ),
+            Ok(Value::from(vec!["https://a.example", "https://b.example"]))
+        );
+        let choices = ["sqlite".to_string(), "postgres".to_string()];
+        let choice = var(VarKind::Choice(choices.to_vec()));
+        assert_eq!(
+            check(&choice, "postgres", &choices),
+            Ok(Value::from("postgres"))
+        );
+        assert_eq!(
+            check(&choice, "mysql", &choices),
+            Err("expected one of sqlite, postgres".to_string())
+        );
+        assert_eq!(
+            check(&var(VarKind::String), "anything", &[]),
+            Ok(Value::from("anything"))
+        );
+    }
+
+    #[test]
+    fn resolves_answers_then_defaults() {
+        let vars = vec![
+            TemplateVar::new("name", VarKind::String, "{{ component }}"),
+            TemplateVar::new("module", VarKind::String, "{{ name | replace('-', '_') }}"),
+            TemplateVar::new("port", VarKind::Int, "8000"),
+            TemplateVar::new(
+                "db",
+                VarKind::Choice(vec!["sqlite".to_string(), "postgres".to_string()]),
+                "",
+            ),
+        ];
+        let mut context = TemplateContext::new("shop", "web-api");
+
+        let resolved = resolve(
+            &vars,
+            None,
+            &answers(&[("port", "9000")]),
+            false,
+            &mut context,
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolved,
+            answers(&[
+                ("name", "web-api"),
+                ("module", "web_api"),
+                ("port", "9000"),
+                ("db", "sqlite"),
+            ])
+        );
+        assert_eq!(context.get("module"), Some(&Value::from("web_api")));
+        assert_eq!(context.get("port"), Some(&Value::from(9000)));
+    }
+
+    #[test]
+    fn publishes_under_namespace() {
+        let vars = vec![
+            TemplateVar::new("project_name", VarKind::String, "Demo"),
+            TemplateVar::new(
+                "slug",
+                VarKind::String,
+                "{{ cookiecutter.project_name | lower }}",
+            ),
+        ];
+        let mut context = TemplateContext::new("shop", "web");
+
+        resolve(
+            &vars,
+            Some("cookiecutter"),
+            &BTreeMap::new(),
+            false,
+            &mut context,
+        )
+        .unwrap();
+
+        let cookiecutter = context.get("cookiecutter").unwrap();
+        assert_eq!(cookiecutter.get_attr("slug").unwrap(), Value::from("demo"));
+        assert_eq!(context.get("slug"), None);
+    }
+
+    #[test]
+    fn rejects_bad_answers_and_failed_validators() {
+        let port = || {
+            TemplateVar::new("port", VarKind::Int, "8000")
+                .validator("{% if port < 1024 %}port must be 1024 or above{% endif %}")
+        };
+        let mut context = TemplateContext::new("shop", "web");
+
+        let error = resolve(
+            &[port()],
+            None,
+            &answers(&[("port", "eighty")]),
+            false,
+            &mut context,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            error.to_string(),
+            "invalid value 'eighty' for port: expected a whole number"
+        );
+
+        let error = resolve(
+            &[port()],
+            None,
+            &answers(&[("port", "80")]),
+            false,
+            &mut context,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "invalid value '80' for port: port must be 1024 or above"
+        );
+
+        assert!(resolve(&[port()], None, &BTreeMap::new(), false, &mut context).is_ok());
+    }
+}
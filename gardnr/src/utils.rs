@@ -1,4 +1,4 @@
-use std::process::Command;
+use std::{path::PathBuf, process::Command};
 
 pub fn is_installed(cmds: &[&str]) -> Option<String> {
     for cmd in cmds {
@@ -18,6 +18,7 @@ pub fn is_installed(cmds: &[&str]) -> Option<String> {
 }
 
 /// Language-specific executable names
+#[allow(dead_code)]
 pub struct TechExecutables<'a> {
     pub python: &'a [&'a str],
     pub node: &'a [&'a str],
@@ -40,3 +41,12 @@ pub const DEFAULT_EXECUTABLES: TechExecutables<'static> = TechExecutables {
     swift: &["swiftc"],
     rust: &["rustc"],
 };
+
+/// gardnr's configuration directory (`$XDG_CONFIG_HOME/gardnr` or `~/.config/gardnr`)
+pub fn config_dir() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(base.join("gardnr"))
+}
@@ -0,0 +1,18 @@
+{%- if drf -%}
+from rest_framework.routers import DefaultRouter
+
+from . import views
+
+router = DefaultRouter()
+router.register("hello", views.HelloViewSet, basename="hello")
+
+urlpatterns = router.urls
+{%- else -%}
+from django.urls import path
+
+from . import views
+
+urlpatterns = [
+    path("", views.index, name="index"),
+]
+{%- endif %}
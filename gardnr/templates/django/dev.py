@@ -0,0 +1,9 @@
+"""Development settings: debug on, local hosts, CORS open to any origin."""
+
+from .base import *  # noqa: F403
+from .base import env
+
+DEBUG = env.bool("DEBUG", default=True)
+ALLOWED_HOSTS = env.list("ALLOWED_HOSTS", default=["localhost", "127.0.0.1"])
+
+CORS_ALLOW_ALL_ORIGINS = True
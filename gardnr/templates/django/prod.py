@@ -0,0 +1,12 @@
+"""Production settings: no debug, secure cookies, HTTPS behind a proxy."""
+
+from .base import *  # noqa: F403
+from .base import env
+
+DEBUG = False
+
+SECURE_PROXY_SSL_HEADER = ("HTTP_X_FORWARDED_PROTO", "https")
+SECURE_SSL_REDIRECT = env.bool("SECURE_SSL_REDIRECT", default=True)
+SECURE_HSTS_SECONDS = env.int("SECURE_HSTS_SECONDS", default=0)
+SESSION_COOKIE_SECURE = True
+CSRF_COOKIE_SECURE = True
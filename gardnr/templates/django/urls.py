@@ -0,0 +1,9 @@
+"""URL configuration for {{ project }}."""
+
+from django.contrib import admin
+from django.urls import include, path
+
+urlpatterns = [
+    path("admin/", admin.site.urls),
+    path("{% if drf %}api/{% endif %}", include("{{ app }}.urls")),
+]
@@ -0,0 +1,21 @@
+{%- if drf -%}
+from rest_framework import viewsets
+from rest_framework.request import Request
+from rest_framework.response import Response
+
+from .serializers import MessageSerializer
+
+
+class HelloViewSet(viewsets.ViewSet):
+    """Sample endpoint at /api/hello/."""
+
+    def list(self, request: Request) -> Response:
+        serializer = MessageSerializer({"message": "Hello from {{ component }}!"})
+        return Response(serializer.data)
+{%- else -%}
+from django.http import HttpRequest, JsonResponse
+
+
+def index(request: HttpRequest) -> JsonResponse:
+    return JsonResponse({"message": "Hello from {{ component }}!"})
+{%- endif %}
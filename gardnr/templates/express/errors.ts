@@ -0,0 +1,26 @@
+import type { ErrorRequestHandler, RequestHandler } from 'express';
+
+/** An error that is sent to the client with its status code and message. */
+export class HttpError extends Error {
+    constructor(
+        public readonly status: number,
+        message: string,
+    ) {
+        super(message);
+        this.name = 'HttpError';
+    }
+}
+
+export const notFound: RequestHandler = (_req, _res, next) => {
+    next(new HttpError(404, 'Route not found'));
+};
+
+export const errorHandler: ErrorRequestHandler = (err, _req, res, _next) => {
+    if (err instanceof HttpError) {
+        res.status(err.status).json({ error: err.message });
+        return;
+    }
+
+    console.error(err instanceof Error ? err.stack : err);
+    res.status(500).json({ error: 'Something went wrong!' });
+};
@@ -0,0 +1,52 @@
+const express = require('express');
+const cors = require('cors');
+const helmet = require('helmet');
+require('dotenv').config();
+
+const app = express();
+const PORT = process.env.PORT || {{ port }};
+
+// Middleware
+app.use(helmet());
+app.use(cors({% if cors_origins != ["*"] %}{ origin: [{% for origin in cors_origins %}'{{ origin }}'{% if not loop.last %}, {% endif %}{% endfor %}] }{% endif %}));
+app.use(express.json());
+app.use(express.urlencoded({ extended: true }));
+
+// Routes
+app.get('/', (req, res) => {
+    res.json({
+        message: 'Hello from {{ component }}!',
+        timestamp: new Date().toISOString()
+    });
+});
+
+app.get('/health', (req, res) => {
+    res.json({ status: 'healthy' });
+});
+
+app.get('/api/status', (req, res) => {
+    res.json({
+        status: 'running',
+        service: '{{ project }}/{{ component }}',
+        version: '{{ version }}',
+        environment: process.env.NODE_ENV || 'development'
+    });
+});
+
+// Error handling middleware
+app.use((err, req, res, next) => {
+    console.error(err.stack);
+    res.status(500).json({ error: 'Something went wrong!' });
+});
+
+// 404 handler
+app.use((req, res) => {
+    res.status(404).json({ error: 'Route not found' });
+});
+
+app.listen(PORT, () => {
+    console.log(`🚀 Server running on http://localhost:${PORT}`);
+    console.log(`📚 Environment: ${process.env.NODE_ENV || 'development'}`);
+});
+
+module.exports = app;
@@ -0,0 +1,46 @@
+import 'dotenv/config';
+import cors from 'cors';
+import express from 'express';
+import helmet from 'helmet';
+import { errorHandler, notFound } from './middleware/errors.js';
+
+const app = express();
+const PORT = Number(process.env.PORT ?? {{ port }});
+
+// Middleware
+app.use(helmet());
+app.use(cors({% if cors_origins != ["*"] %}{ origin: [{% for origin in cors_origins %}'{{ origin }}'{% if not loop.last %}, {% endif %}{% endfor %}] }{% endif %}));
+app.use(express.json());
+app.use(express.urlencoded({ extended: true }));
+
+// Routes
+app.get('/', (_req, res) => {
+    res.json({
+        message: 'Hello from {{ component }}!',
+        timestamp: new Date().toISOString(),
+    });
+});
+
+app.get('/health', (_req, res) => {
+    res.json({ status: 'healthy' });
+});
+
+app.get('/api/status', (_req, res) => {
+    res.json({
+        status: 'running',
+        service: '{{ project }}/{{ component }}',
+        version: '{{ version }}',
+        environment: process.env.NODE_ENV ?? 'development',
+    });
+});
+
+// 404 and error handlers go last
+app.use(notFound);
+app.use(errorHandler);
+
+app.listen(PORT, () => {
+    console.log(`🚀 Server running on http://localhost:${PORT}`);
+    console.log(`📚 Environment: ${process.env.NODE_ENV ?? 'development'}`);
+});
+
+export default app;
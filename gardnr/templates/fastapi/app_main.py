@@ -0,0 +1,23 @@
+from fastapi import FastAPI
+from fastapi.middleware.cors import CORSMiddleware
+
+from .api.routers import health, hello
+from .config import settings
+
+app = FastAPI(title=settings.app_name, version="{{ version }}", debug=settings.debug)
+
+app.add_middleware(
+    CORSMiddleware,
+    allow_origins=settings.cors_origins,
+    allow_credentials=True,
+    allow_methods=["*"],
+    allow_headers=["*"],
+)
+
+app.include_router(health.router)
+app.include_router(hello.router, prefix="/api")
+
+
+@app.get("/")
+async def read_root():
+    return {"message": "Hello from {{ component }}!"}
@@ -0,0 +1,17 @@
+from pydantic_settings import BaseSettings, SettingsConfigDict
+
+
+class Settings(BaseSettings):
+    """Application settings, read from the environment and `.env`."""
+
+    model_config = SettingsConfigDict(env_file=".env", extra="ignore")
+
+    app_name: str = "{{ title }}"
+    debug: bool = False
+    cors_origins: list[str] = {{ cors_origins | jsonify }}
+{%- if database %}
+    database_url: str = "sqlite:///./app.db"
+{%- endif %}
+
+
+settings = Settings()
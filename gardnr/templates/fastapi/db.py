@@ -0,0 +1,19 @@
+from collections.abc import Iterator
+
+from sqlalchemy import create_engine
+from sqlalchemy.orm import DeclarativeBase, Session, sessionmaker
+
+from .config import settings
+
+engine = create_engine(settings.database_url)
+SessionLocal = sessionmaker(bind=engine, autoflush=False)
+
+
+class Base(DeclarativeBase):
+    """Base class of the models; Alembic autogenerates migrations from its metadata."""
+
+
+def get_db() -> Iterator[Session]:
+    """Request-scoped session, for use with `Depends(get_db)`."""
+    with SessionLocal() as session:
+        yield session
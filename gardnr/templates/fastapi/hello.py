@@ -0,0 +1,8 @@
+from fastapi import APIRouter
+
+router = APIRouter(tags=["hello"])
+
+
+@router.get("/hello")
+async def api_hello():
+    return {"message": "Hello from {{ title }}!"}
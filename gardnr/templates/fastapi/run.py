@@ -0,0 +1,10 @@
+"""Entry point for `python main.py` and `uvicorn main:app`; the app lives in app/main.py."""
+
+from app.main import app
+
+__all__ = ["app"]
+
+if __name__ == "__main__":
+    import uvicorn
+
+    uvicorn.run("app.main:app", host="0.0.0.0", port={{ port }}, reload=True)
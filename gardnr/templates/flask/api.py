@@ -0,0 +1,13 @@
+from flask import Blueprint, jsonify
+
+api = Blueprint("api", __name__)
+
+
+@api.get("/health")
+def health():
+    return jsonify({"status": "healthy"})
+
+
+@api.get("/api/hello")
+def api_hello():
+    return jsonify({"message": "Hello from {{ title }}!"})
@@ -0,0 +1,20 @@
+from flask import Flask, jsonify
+from flask_cors import CORS
+
+app = Flask(__name__)
+CORS(app, origins={{ cors_origins | jsonify }})
+
+@app.route('/')
+def home():
+    return jsonify({"message": "Hello from {{ component }}!"})
+
+@app.route('/health')
+def health():
+    return jsonify({"status": "healthy"})
+
+@app.route('/api/hello')
+def api_hello():
+    return jsonify({"message": "Hello from {{ title }}!"})
+
+if __name__ == '__main__':
+    app.run(debug=True, host='0.0.0.0', port={{ port }})
@@ -0,0 +1,25 @@
+import os
+
+from flask import Flask, jsonify
+from flask_cors import CORS
+
+from .config import CONFIGS
+
+
+def create_app(config_name: str | None = None) -> Flask:
+    """Create the application with the config named by `config_name`, else
+    by the FLASK_CONFIG environment variable."""
+    app = Flask(__name__)
+    app.config.from_object(CONFIGS[config_name or os.environ.get("FLASK_CONFIG", "development")])
+
+    CORS(app, origins=app.config["CORS_ORIGINS"])
+
+    from .api import api
+
+    app.register_blueprint(api)
+
+    @app.get("/")
+    def home():
+        return jsonify({"message": "Hello from {{ component }}!"})
+
+    return app
@@ -0,0 +1,10 @@
+def test_health(client):
+    response = client.get("/health")
+    assert response.status_code == 200
+    assert response.get_json() == {"status": "healthy"}
+
+
+def test_hello(client):
+    response = client.get("/api/hello")
+    assert response.status_code == 200
+    assert response.get_json() == {"message": "Hello from {{ title }}!"}
@@ -0,0 +1,8 @@
+"""WSGI entry point, e.g. `gunicorn wsgi:app`."""
+
+from app import create_app
+
+app = create_app()
+
+if __name__ == "__main__":
+    app.run(host="0.0.0.0", port={{ port }})
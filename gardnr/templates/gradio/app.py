@@ -0,0 +1,17 @@
+import gradio as gr
+
+
+def greet(name: str, intensity: int) -> str:
+    return "Hello, " + name + "!" * intensity
+
+
+demo = gr.Interface(
+    fn=greet,
+    inputs=[gr.Textbox(label="Name"), gr.Slider(1, 10, value=1, step=1, label="Intensity")],
+    outputs=gr.Textbox(label="Greeting"),
+    title="{{ title }}",
+    description="Demo interface of {{ component }}.",
+)
+
+if __name__ == "__main__":
+    demo.launch(server_name="0.0.0.0", server_port={{ port }})
@@ -0,0 +1,28 @@
+from litestar import Litestar, get
+from litestar.config.cors import CORSConfig
+
+
+@get("/")
+async def home() -> dict[str, str]:
+    return {"message": "Hello from {{ component }}!"}
+
+
+@get("/health")
+async def health() -> dict[str, str]:
+    return {"status": "healthy"}
+
+
+@get("/api/hello")
+async def api_hello() -> dict[str, str]:
+    return {"message": "Hello from {{ title }}!"}
+
+
+app = Litestar(
+    route_handlers=[home, health, api_hello],
+    cors_config=CORSConfig(allow_origins={{ cors_origins | jsonify }}),
+)
+
+if __name__ == "__main__":
+    import uvicorn
+
+    uvicorn.run("app:app", host="0.0.0.0", port={{ port }}, reload=True)
@@ -0,0 +1,8 @@
+"""Locations of the project's data and models."""
+
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parents[2]
+DATA_RAW = ROOT / "data" / "raw"
+DATA_PROCESSED = ROOT / "data" / "processed"
+MODELS = ROOT / "models"
@@ -0,0 +1,27 @@
+from pyramid.config import Configurator
+from pyramid.view import view_config
+
+@view_config(route_name='home', renderer='json')
+def home_view(request):
+    return {'message': 'Hello from {{ component }}!'}
+
+@view_config(route_name='health', renderer='json')
+def health_view(request):
+    return {'status': 'healthy'}
+
+def main():
+    config = Configurator()
+
+    config.add_route('home', '/')
+    config.add_route('health', '/health')
+    config.scan()
+
+    app = config.make_wsgi_app()
+    return app
+
+if __name__ == '__main__':
+    from wsgiref.simple_server import make_server
+    app = main()
+    server = make_server('0.0.0.0', {{ port }}, app)
+    print('Serving on http://0.0.0.0:{{ port }}')
+    server.serve_forever()
@@ -0,0 +1,8 @@
+"""{{ title }} command-line interface."""
+
+from importlib.metadata import PackageNotFoundError, version
+
+try:
+    __version__ = version("{{ distribution }}")
+except PackageNotFoundError:  # running from a checkout that is not installed
+    __version__ = "0.0.0"
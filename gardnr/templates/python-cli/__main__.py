@@ -0,0 +1,6 @@
+"""Entry point for `python -m {{ package }}` and the `{{ script }}` command."""
+
+from .cli import main
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,38 @@
+import click
+from click.shell_completion import get_completion_class
+
+from . import __version__
+
+
+@click.group(context_settings={"help_option_names": ["-h", "--help"]})
+@click.version_option(__version__, "--version", "-V", prog_name="{{ script }}")
+def cli() -> None:
+    """{{ title }} command-line interface."""
+
+
+@cli.command()
+@click.argument("name", default="world")
+def hello(name: str) -> None:
+    """Say hello."""
+    click.echo(f"Hello, {name}!")
+
+
+@cli.command()
+@click.argument("name", default="world")
+@click.option("--formal", is_flag=True, help="Say it formally.")
+def goodbye(name: str, formal: bool) -> None:
+    """Say goodbye."""
+    click.echo(f"Farewell, {name}." if formal else f"Bye, {name}!")
+
+
+@cli.command()
+@click.argument("shell", type=click.Choice(["bash", "zsh", "fish"]))
+def completion(shell: str) -> None:
+    """Print the completion script for SHELL, e.g. `eval "$({{ script }} completion bash)"`."""
+    completion_class = get_completion_class(shell)
+    assert completion_class is not None
+    click.echo(completion_class(cli, {}, "{{ script }}", "{{ complete_var }}").source())
+
+
+def main() -> None:
+    cli(prog_name="{{ script }}")
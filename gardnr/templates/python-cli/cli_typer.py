@@ -0,0 +1,44 @@
+import typer
+
+from . import __version__
+
+app = typer.Typer(help="{{ title }} command-line interface.", no_args_is_help=True)
+
+
+def version_callback(value: bool) -> None:
+    if value:
+        typer.echo(f"{{ script }} {__version__}")
+        raise typer.Exit()
+
+
+@app.callback()
+def callback(
+    version: bool = typer.Option(
+        False,
+        "--version",
+        "-V",
+        callback=version_callback,
+        is_eager=True,
+        help="Show the version and exit.",
+    ),
+) -> None:
+    """{{ title }} command-line interface."""
+
+
+@app.command()
+def hello(name: str = typer.Argument("world", help="Who to greet.")) -> None:
+    """Say hello."""
+    typer.echo(f"Hello, {name}!")
+
+
+@app.command()
+def goodbye(
+    name: str = typer.Argument("world", help="Who to say goodbye to."),
+    formal: bool = typer.Option(False, "--formal", help="Say it formally."),
+) -> None:
+    """Say goodbye."""
+    typer.echo(f"Farewell, {name}." if formal else f"Bye, {name}!")
+
+
+def main() -> None:
+    app(prog_name="{{ script }}")
@@ -0,0 +1,24 @@
+from typer.testing import CliRunner
+
+from {{ package }} import __version__
+from {{ package }}.cli import app
+
+runner = CliRunner()
+
+
+def test_hello() -> None:
+    result = runner.invoke(app, ["hello", "Ada"])
+    assert result.exit_code == 0
+    assert "Hello, Ada!" in result.output
+
+
+def test_goodbye_formal() -> None:
+    result = runner.invoke(app, ["goodbye", "Ada", "--formal"])
+    assert result.exit_code == 0
+    assert "Farewell, Ada." in result.output
+
+
+def test_version() -> None:
+    result = runner.invoke(app, ["--version"])
+    assert result.exit_code == 0
+    assert __version__ in result.output
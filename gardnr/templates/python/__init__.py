@@ -0,0 +1,3 @@
+"""{{ title }} - {{ tech }} application."""
+
+__version__ = "{{ version }}"
@@ -0,0 +1,10 @@
+"""Entry point for `python -m {{ package }}` and the `{{ script }}` command."""
+
+
+def main() -> None:
+    print("Hello from {{ component }}!")
+    # Add your {{ tech }} code here
+
+
+if __name__ == "__main__":
+    main()
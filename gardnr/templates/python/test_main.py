@@ -0,0 +1,8 @@
+import pytest
+
+from {{ package }}.__main__ import main
+
+
+def test_main(capsys: pytest.CaptureFixture[str]) -> None:
+    main()
+    assert capsys.readouterr().out == "Hello from {{ component }}!\n"
@@ -0,0 +1,22 @@
+from quart import Quart, jsonify
+
+app = Quart(__name__)
+
+
+@app.get("/")
+async def home():
+    return jsonify({"message": "Hello from {{ component }}!"})
+
+
+@app.get("/health")
+async def health():
+    return jsonify({"status": "healthy"})
+
+
+@app.get("/api/hello")
+async def api_hello():
+    return jsonify({"message": "Hello from {{ title }}!"})
+
+
+if __name__ == "__main__":
+    app.run(host="0.0.0.0", port={{ port }}, debug=True)
@@ -0,0 +1,23 @@
+from sanic import Request, Sanic
+from sanic.response import JSONResponse, json
+
+app = Sanic("{{ component | slugify }}")
+
+
+@app.get("/")
+async def home(request: Request) -> JSONResponse:
+    return json({"message": "Hello from {{ component }}!"})
+
+
+@app.get("/health")
+async def health(request: Request) -> JSONResponse:
+    return json({"status": "healthy"})
+
+
+@app.get("/api/hello")
+async def api_hello(request: Request) -> JSONResponse:
+    return json({"message": "Hello from {{ title }}!"})
+
+
+if __name__ == "__main__":
+    app.run(host="0.0.0.0", port={{ port }}, dev=True)
@@ -0,0 +1,35 @@
+from starlette.applications import Starlette
+from starlette.middleware import Middleware
+from starlette.middleware.cors import CORSMiddleware
+from starlette.requests import Request
+from starlette.responses import JSONResponse
+from starlette.routing import Route
+
+
+async def home(request: Request) -> JSONResponse:
+    return JSONResponse({"message": "Hello from {{ component }}!"})
+
+
+async def health(request: Request) -> JSONResponse:
+    return JSONResponse({"status": "healthy"})
+
+
+async def api_hello(request: Request) -> JSONResponse:
+    return JSONResponse({"message": "Hello from {{ title }}!"})
+
+
+app = Starlette(
+    routes=[
+        Route("/", home),
+        Route("/health", health),
+        Route("/api/hello", api_hello),
+    ],
+    middleware=[
+        Middleware(CORSMiddleware, allow_origins={{ cors_origins | jsonify }}, allow_methods=["*"]),
+    ],
+)
+
+if __name__ == "__main__":
+    import uvicorn
+
+    uvicorn.run("app:app", host="0.0.0.0", port={{ port }}, reload=True)
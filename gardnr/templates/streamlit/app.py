@@ -0,0 +1,13 @@
+import pandas as pd
+import streamlit as st
+
+st.set_page_config(page_title="{{ title }}", layout="wide")
+
+st.title("{{ title }}")
+st.write("Hello from {{ component }}! Edit `app.py` and the page reloads.")
+
+rows = st.slider("Rows", min_value=5, max_value=100, value=20)
+data = pd.DataFrame({"x": range(rows), "y": [n * n for n in range(rows)]})
+
+st.line_chart(data, x="x", y="y")
+st.dataframe(data, use_container_width=True)